@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::logic::{GameError, GameState, Move};
+
+use super::Bot;
+
+/// Enum to represent how strong the computer player is.
+#[derive(Serialize, Deserialize, ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    #[default]
+    Perfect,
+}
+
+/// Implementation for Difficulty mapping each level to the limits placed on the computer player.
+impl Difficulty {
+    /// Every difficulty, from easiest to hardest.
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Perfect,
+    ];
+
+    /// How many moves ahead minimax looks (None searches to the end of the game).
+    pub fn max_depth(self) -> Option<i32> {
+        match self {
+            Difficulty::Easy => Some(1),
+            Difficulty::Medium => Some(2),
+            Difficulty::Hard => Some(4),
+            Difficulty::Perfect => None,
+        }
+    }
+    /// Chance of playing a random move instead of the best one.
+    pub fn blunder_rate(self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.4,
+            Difficulty::Medium => 0.15,
+            Difficulty::Hard => 0.05,
+            Difficulty::Perfect => 0.0,
+        }
+    }
+    /// How long MCTS searches for each move.
+    pub fn time_budget(self) -> Duration {
+        match self {
+            Difficulty::Easy => Duration::from_millis(50),
+            Difficulty::Medium => Duration::from_millis(200),
+            Difficulty::Hard => Duration::from_millis(1000),
+            Difficulty::Perfect => Duration::from_millis(3000),
+        }
+    }
+}
+
+/// Implementation to display difficulty as a string ("easy", "medium", "hard" or "perfect").
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Medium => write!(f, "medium"),
+            Difficulty::Hard => write!(f, "hard"),
+            Difficulty::Perfect => write!(f, "perfect"),
+        }
+    }
+}
+
+/// Bot that wraps another bot and sometimes plays a random move instead of the wrapped bot's choice.
+pub struct Blundering {
+    bot: Box<dyn Bot>,
+    blunder_rate: f64,
+    rng: StdRng,
+}
+
+/// Implementation for Blundering containing a constructor.
+impl Blundering {
+    /// Creates a bot that plays a random move with a chance of blunder_rate, and otherwise asks bot.
+    pub fn new(bot: Box<dyn Bot>, blunder_rate: f64, seed: Option<u64>) -> Self {
+        let rng: StdRng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Blundering {
+            bot,
+            blunder_rate,
+            rng,
+        }
+    }
+}
+
+/// Implementation of 'Bot' for Blundering that rolls for a blunder before asking the wrapped bot.
+impl Bot for Blundering {
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>> {
+        if self.blunder_rate > 0.0 && self.rng.gen_bool(self.blunder_rate.min(1.0)) {
+            return match state.legal_moves().choose(&mut self.rng) {
+                Some(&mv) => Ok(mv),
+                None => Err(Box::new(GameError::GameOver)),
+            };
+        }
+        self.bot.choose_move(state)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::logic::{GameState, Move, Player};
+
+use super::{minimax::WIN_SCORE, solver::FULL_SEARCH_LIMIT, Minimax, Solution, Solver};
+
+/// How many moves ahead the hint looks on boards too big to search to the end.
+const HINT_DEPTH: i32 = 3;
+
+/// Holds the engine's best move for the current player and what it thinks of the position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hint {
+    pub best_move: Move,
+    /// Negamax score of the best move from the current player's point of view.
+    pub score: i32,
+    /// True if the search went all the way to the end of the game, so the score is exact.
+    pub exact: bool,
+}
+
+/// Implementation for Hint containing a function to describe the evaluation.
+impl Hint {
+    /// Describes the evaluation, e.g. "X wins in 3" or "draw with best play".
+    pub fn evaluation(&self) -> String {
+        let player: Player = self.best_move.player;
+        // Wins are scored as WIN_SCORE minus the number of moves (by both players) it takes to get there.
+        let plies: i32 = WIN_SCORE - self.score.abs();
+        match self.score {
+            // Count only the winner's moves; the current player moves first, so they make the odd ones.
+            score if score > 0 => format!("{} wins in {}", player, (plies + 1) / 2),
+            score if score < 0 => format!("{} wins in {}", player.other(), plies / 2),
+            _ if self.exact => String::from("draw with best play"),
+            _ => format!("no forced win within {} moves", HINT_DEPTH),
+        }
+    }
+}
+
+/// Function that searches the position for the current player's best move, returning None if the game is over.
+/// Positions with at most FULL_SEARCH_LIMIT legal moves are solved; bigger ones are only searched HINT_DEPTH moves ahead.
+pub fn hint(state: &GameState) -> Option<Hint> {
+    let exact: bool = state.legal_moves().len() <= FULL_SEARCH_LIMIT;
+    let (best_move, score) = if exact {
+        let solution: Solution = Solver::new().solve(state).ok()?;
+        (*solution.best_moves.first()?, solution.score)
+    } else {
+        Minimax::with_max_depth(Some(HINT_DEPTH))
+            .best_move(state)
+            .ok()?
+    };
+    Some(Hint {
+        best_move,
+        score,
+        exact,
+    })
+}
@@ -0,0 +1,198 @@
+use std::time::{Duration, Instant};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::logic::{GameError, GameState, GameStatus, Move, Player};
+
+use super::Bot;
+
+/// Number of iterations to search for when neither an iteration nor a time budget is given.
+pub const DEFAULT_ITERATIONS: u32 = 5000;
+
+/// Exploration constant for UCT; higher values try less visited moves more often.
+const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+/// Bot that picks moves with Monte Carlo Tree Search, playing random games out from each position.
+/// Unlike minimax it doesn't need to search every continuation, so it stays useful on large boards.
+#[derive(Clone, Debug)]
+pub struct Mcts {
+    iterations: Option<u32>,
+    time_limit: Option<Duration>,
+    rng: StdRng,
+}
+
+/// A single position in the search tree.
+struct Node {
+    /// The move that led to this position (None for the root).
+    mv: Option<Move>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// Moves from this position that haven't been added to the tree yet.
+    untried: Vec<Move>,
+    visits: u32,
+    /// Total score of the playouts through this node for the player who made mv (1 for a win, 0.5 for a draw).
+    score: f64,
+}
+
+/// Implementation for Mcts containing a constructor and the search itself.
+impl Mcts {
+    /// Creates a new MCTS bot that stops after the given number of iterations or amount of time, whichever comes first.
+    /// If neither is given it stops after DEFAULT_ITERATIONS. Giving a seed makes the bot play the same way every time.
+    pub fn new(iterations: Option<u32>, time_limit: Option<Duration>, seed: Option<u64>) -> Self {
+        let rng: StdRng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let iterations: Option<u32> = match (iterations, time_limit) {
+            (None, None) => Some(DEFAULT_ITERATIONS),
+            (iterations, _) => iterations,
+        };
+        Mcts {
+            iterations,
+            time_limit,
+            rng,
+        }
+    }
+
+    /// Returns true once the iteration or time budget has run out.
+    fn out_of_budget(&self, iterations: u32, start: Instant) -> bool {
+        self.iterations
+            .is_some_and(|limit: u32| iterations >= limit)
+            || self
+                .time_limit
+                .is_some_and(|limit: Duration| start.elapsed() >= limit)
+    }
+
+    /// Runs the search from the given state and returns the most visited move.
+    fn search(&mut self, state: &GameState) -> Option<Move> {
+        let mut tree: Vec<Node> = vec![Node {
+            mv: None,
+            parent: None,
+            children: Vec::new(),
+            untried: state.legal_moves(),
+            visits: 0,
+            score: 0.0,
+        }];
+        let start: Instant = Instant::now();
+        let mut iterations: u32 = 0;
+        // Always run at least one iteration so there is a move to return.
+        while iterations == 0 || !self.out_of_budget(iterations, start) {
+            iterations += 1;
+            let mut node: usize = 0;
+            let mut position: GameState = state.clone();
+
+            // Selection: walk down the tree picking the child with the best UCT value until a node with untried moves (or a finished game) is reached.
+            while tree[node].untried.is_empty() && !tree[node].children.is_empty() {
+                node = best_child(&tree, node);
+                if let Some(mv) = tree[node].mv {
+                    let _ = position.apply_move(mv);
+                }
+            }
+
+            // Expansion: add one random untried move to the tree.
+            if !tree[node].untried.is_empty() {
+                let pick: usize = self.rng.gen_range(0..tree[node].untried.len());
+                let mv: Move = tree[node].untried.swap_remove(pick);
+                let _ = position.apply_move(mv);
+                tree.push(Node {
+                    mv: Some(mv),
+                    parent: Some(node),
+                    children: Vec::new(),
+                    untried: position.legal_moves(),
+                    visits: 0,
+                    score: 0.0,
+                });
+                let child: usize = tree.len() - 1;
+                tree[node].children.push(child);
+                node = child;
+            }
+
+            // Simulation: play random moves until the game is over.
+            while let Some(&mv) = position.legal_moves().choose(&mut self.rng) {
+                let _ = position.apply_move(mv);
+            }
+
+            // Backpropagation: credit the result to every node on the way back up to the root.
+            let mut current: Option<usize> = Some(node);
+            while let Some(index) = current {
+                let node: &mut Node = &mut tree[index];
+                node.visits += 1;
+                if let Some(mv) = node.mv {
+                    node.score += match position.status {
+                        GameStatus::Won(winner) if winner == mv.player => 1.0,
+                        GameStatus::Draw => 0.5,
+                        _ => 0.0,
+                    };
+                }
+                current = node.parent;
+            }
+        }
+        // Play the most visited move, since it's the one the search is most confident in.
+        tree[0]
+            .children
+            .iter()
+            .max_by_key(|&&child: &&usize| tree[child].visits)
+            .and_then(|&child: &usize| tree[child].mv)
+    }
+}
+
+/// Function that returns the child of node with the highest UCT value.
+fn best_child(tree: &[Node], node: usize) -> usize {
+    let parent_visits: f64 = f64::from(tree[node].visits.max(1)).ln();
+    let uct = |child: usize| -> f64 {
+        let visits: f64 = f64::from(tree[child].visits.max(1));
+        tree[child].score / visits + EXPLORATION * (parent_visits / visits).sqrt()
+    };
+    tree[node]
+        .children
+        .iter()
+        .copied()
+        .max_by(|&a: &usize, &b: &usize| uct(a).total_cmp(&uct(b)))
+        .unwrap_or(node)
+}
+
+/// Function that returns a move for player that wins the game on the spot, if there is one.
+fn winning_move(state: &GameState, player: Player) -> Option<Move> {
+    // Look at the board as if it were player's turn.
+    let mut position: GameState = state.clone();
+    position.current_player = player;
+    position.legal_moves().into_iter().find(|&mv: &Move| {
+        let mut child: GameState = position.clone();
+        matches!(child.apply_move(mv), Ok(GameStatus::Won(winner)) if winner == player)
+    })
+}
+
+/// Function that returns a move for the current player in the cell of the opponent's winning move threat, if one of their moves there doesn't lose on the spot.
+/// The block is one of the current player's own legal moves, since the threat may be marked with a symbol or number only the opponent can play, or the same symbol may complete the opponent's line whoever plays it (as in Order and Chaos).
+fn blocking_move(state: &GameState, threat: Move) -> Option<Move> {
+    let player: Player = state.current_player;
+    state
+        .legal_moves()
+        .into_iter()
+        .filter(|mv: &Move| (mv.x, mv.y, mv.z) == (threat.x, threat.y, threat.z))
+        .find(|&mv: &Move| {
+            let mut child: GameState = state.clone();
+            !matches!(child.apply_move(mv), Ok(GameStatus::Won(winner)) if winner != player)
+        })
+}
+
+/// Implementation of 'Bot' for Mcts that takes an immediate win or blocks an immediate loss, and otherwise searches.
+impl Bot for Mcts {
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>> {
+        let player: Player = state.current_player;
+        if state.status != GameStatus::Ongoing {
+            return Err(Box::new(GameError::GameOver));
+        }
+        // Random playouts are slow to notice one-move wins and losses on big boards, so check for them first.
+        if let Some(mv) = winning_move(state, player) {
+            return Ok(mv);
+        }
+        if let Some(mv) = winning_move(state, player.other())
+            .and_then(|threat: Move| blocking_move(state, threat))
+        {
+            return Ok(mv);
+        }
+        self.search(state)
+            .ok_or_else(|| Box::new(GameError::GameOver) as Box<dyn std::error::Error>)
+    }
+}
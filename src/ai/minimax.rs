@@ -0,0 +1,101 @@
+use crate::logic::{GameError, GameState, GameStatus, Move, Player};
+
+use super::Bot;
+
+/// Score of a win, reduced by the number of moves it takes so that quicker wins (and slower losses) are preferred.
+pub const WIN_SCORE: i32 = 1000;
+
+/// Bot that searches every possible continuation of the game using negamax with alpha-beta pruning.
+/// Without a depth limit this plays perfectly on a 3x3 board.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Minimax {
+    max_depth: Option<i32>,
+}
+
+/// Implementation for Minimax containing constructors.
+impl Minimax {
+    /// Creates a new minimax bot that searches to the end of the game.
+    pub fn new() -> Self {
+        Minimax { max_depth: None }
+    }
+    /// Creates a new minimax bot that looks at most max_depth moves ahead (or to the end of the game if None).
+    pub fn with_max_depth(max_depth: Option<i32>) -> Self {
+        Minimax { max_depth }
+    }
+}
+
+/// Implementation of 'Bot' for Minimax that picks the move with the best negamax score.
+impl Bot for Minimax {
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>> {
+        let (mv, _) = self.best_move(state)?;
+        Ok(mv)
+    }
+}
+
+/// Implementation for Minimax containing the root of the search.
+impl Minimax {
+    /// Returns the best move for the current player along with its negamax score from their point of view.
+    pub fn best_move(&self, state: &GameState) -> Result<(Move, i32), Box<dyn std::error::Error>> {
+        let player: Player = state.current_player;
+        let mut best: Option<(i32, Move)> = None;
+        // Start with the widest possible window (the bounds are kept one away from i32::MIN so negating them can't overflow).
+        let mut alpha: i32 = -i32::MAX;
+        let beta: i32 = i32::MAX;
+        // Score each legal move, keeping the first of the best scoring ones.
+        for mv in state.legal_moves() {
+            let mut child: GameState = state.clone();
+            child.apply_move(mv)?;
+            let score: i32 = -negamax(&child, player.other(), 1, self.max_depth, -beta, -alpha);
+            if best.is_none_or(|(best_score, _)| score > best_score) {
+                best = Some((score, mv));
+            }
+            alpha = alpha.max(score);
+        }
+        // If there are no legal moves the game must be over.
+        match best {
+            Some((score, mv)) => Ok((mv, score)),
+            None => Err(Box::new(GameError::GameOver)),
+        }
+    }
+}
+
+/// Function that scores the state from the point of view of player, searching every continuation of the game (up to max_depth moves ahead, if given).
+/// Positions past max_depth are scored as 0, the same as a draw.
+/// Branches that can't change the result (anything outside of alpha..beta) are pruned.
+pub fn negamax(
+    state: &GameState,
+    player: Player,
+    depth: i32,
+    max_depth: Option<i32>,
+    mut alpha: i32,
+    beta: i32,
+) -> i32 {
+    match state.status {
+        // A finished game is scored straight away; wins are worth less the longer they take.
+        GameStatus::Won(winner) if winner == player => WIN_SCORE - depth,
+        GameStatus::Won(_) => -(WIN_SCORE - depth),
+        GameStatus::Draw => 0,
+        // Nothing is known about a position past the search horizon.
+        GameStatus::Ongoing if max_depth.is_some_and(|max_depth: i32| depth >= max_depth) => 0,
+        GameStatus::Ongoing => {
+            let mut best: i32 = -i32::MAX;
+            for mv in state.legal_moves() {
+                let mut child: GameState = state.clone();
+                // Legal moves can't be rejected, so skip the move in the (impossible) case that it is.
+                if child.apply_move(mv).is_err() {
+                    continue;
+                }
+                // The other player's best score is this player's worst.
+                let score: i32 =
+                    -negamax(&child, player.other(), depth + 1, max_depth, -beta, -alpha);
+                best = best.max(score);
+                alpha = alpha.max(score);
+                // If the other player already has a better option elsewhere they'll never allow this position.
+                if alpha >= beta {
+                    break;
+                }
+            }
+            best
+        }
+    }
+}
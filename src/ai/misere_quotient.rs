@@ -0,0 +1,215 @@
+use crate::logic::notakto::NOTAKTO_SIZE;
+use crate::logic::{
+    Cell, GameError, GameState, GameStatus, Geometry, Move, NotaktoState, Symmetry,
+};
+
+/// An element of the misère quotient of Notakto, the commutative monoid
+/// Q = ⟨a, b, c, d | a² = 1, b³ = b, b²c = c, c³ = ac², b²d = d, cd = ad, d² = c²⟩
+/// of eighteen elements worked out by Plambeck and Whitehead, stored as the powers of a, b, c and d in the normal form reduce() gives.
+/// Every 3x3 board has a value in Q, the value of a game of several boards is the product of theirs, and the player to move loses exactly when that product is one of a, b², bc or c².
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Quotient {
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+}
+
+/// The identity of Q, which is the value of a dead board (or no boards at all).
+const ONE: Quotient = Quotient::new(0, 0, 0, 0);
+
+/// The value of every board up to symmetry, with the boards given as bit masks of their X's (bit y * 3 + x for the cell (x, y)).
+/// Only the smallest mask each board can be turned or flipped into is listed; see canonical_mask().
+const BOARD_VALUES: [(Quotient, &[u16]); 8] = [
+    // The empty board.
+    (Quotient::new(0, 0, 1, 0), &[0b000000000]),
+    // A single X in the centre.
+    (Quotient::new(0, 0, 2, 0), &[0b000010000]),
+    (ONE, &[0b000000001, 0b000000010, 0b001100010]),
+    (
+        Quotient::new(1, 0, 0, 0),
+        &[
+            0b000001010,
+            0b000001101,
+            0b000010101,
+            0b000011011,
+            0b000011100,
+            0b000101000,
+            0b000101011,
+            0b001000100,
+            0b001100001,
+            0b001100110,
+            0b001101100,
+            0b001110011,
+            0b010101010,
+            0b010101101,
+            0b011100101,
+            0b011101110,
+            0b101000101,
+        ],
+    ),
+    (
+        Quotient::new(0, 1, 0, 0),
+        &[
+            0b000000101,
+            0b000001011,
+            0b000001100,
+            0b000010001,
+            0b000010010,
+            0b000011101,
+            0b000011110,
+            0b000101010,
+            0b000101101,
+            0b001100011,
+            0b001100101,
+            0b001101110,
+            0b001110001,
+            0b001110010,
+            0b010101011,
+        ],
+    ),
+    (
+        Quotient::new(1, 1, 0, 0),
+        &[
+            0b000010011,
+            0b000011010,
+            0b001000101,
+            0b001001110,
+            0b001101010,
+        ],
+    ),
+    (Quotient::new(0, 0, 0, 1), &[0b000000011]),
+    (
+        Quotient::new(1, 0, 0, 1),
+        &[0b000001110, 0b000101001, 0b001000110],
+    ),
+];
+
+/// Implementation for Quotient containing functions to reduce elements, tell losing positions apart and find the value of boards and games.
+impl Quotient {
+    /// Creates the element a^a b^b c^c d^d, reduced to normal form.
+    const fn new(a: u8, b: u8, c: u8, d: u8) -> Self {
+        Quotient::reduce(a, b, c, d)
+    }
+
+    /// Rewrites a^a b^b c^c d^d with the relations of Q until none of them apply, which leaves a power of at most one for a and d and at most two for b and c.
+    const fn reduce(mut a: u8, mut b: u8, mut c: u8, mut d: u8) -> Self {
+        loop {
+            a %= 2;
+            if b >= 3 || (b >= 2 && (c >= 1 || d >= 1)) {
+                // b³ = b, b²c = c and b²d = d,
+                b -= 2;
+            } else if c >= 3 || (c >= 1 && d >= 1) {
+                // c³ = ac² and cd = ad,
+                c -= 1;
+                a += 1;
+            } else if d >= 2 {
+                // and d² = c².
+                d -= 2;
+                c += 2;
+            } else {
+                return Quotient { a, b, c, d };
+            }
+        }
+    }
+
+    /// Returns true if the player to move in a game with this value loses with perfect play (the P-positions of Q are a, b², bc and c²).
+    pub fn is_losing(self) -> bool {
+        matches!(
+            (self.a, self.b, self.c, self.d),
+            (1, 0, 0, 0) | (0, 2, 0, 0) | (0, 1, 1, 0) | (0, 0, 2, 0)
+        )
+    }
+
+    /// Returns the value of a single board; a dead board is out of the game, so it has the value of no board at all.
+    pub fn of_board(board: &GameState) -> Self {
+        if board.status != GameStatus::Ongoing {
+            return ONE;
+        }
+        let mask: u16 = canonical_mask(board);
+        BOARD_VALUES
+            .iter()
+            .find(|(_, masks): &&(Quotient, &[u16])| masks.contains(&mask))
+            .map_or(ONE, |(value, _): &(Quotient, &[u16])| *value)
+    }
+
+    /// Returns the value of a whole game, the product of the values of its boards.
+    pub fn of_game(state: &NotaktoState) -> Self {
+        state
+            .boards
+            .iter()
+            .map(Quotient::of_board)
+            .fold(ONE, |product: Quotient, value: Quotient| product * value)
+    }
+}
+
+/// Implementation of std::ops::Mul for Quotient to multiply elements, which is how the values of boards combine.
+impl std::ops::Mul for Quotient {
+    type Output = Quotient;
+
+    fn mul(self, other: Quotient) -> Quotient {
+        Quotient::reduce(
+            self.a + other.a,
+            self.b + other.b,
+            self.c + other.c,
+            self.d + other.d,
+        )
+    }
+}
+
+/// Function that returns the bit mask of a board's X's, turned and flipped into whichever of its eight symmetries gives the smallest mask.
+fn canonical_mask(board: &GameState) -> u16 {
+    let geometry: Geometry = Geometry::new(NOTAKTO_SIZE, NOTAKTO_SIZE);
+    Symmetry::ALL
+        .iter()
+        .map(|symmetry: &Symmetry| {
+            (0..geometry.len())
+                .filter(|&index: &usize| board.board[index] != Cell::Empty)
+                .map(|index: usize| {
+                    let (x, y) = geometry.coords(index);
+                    let (x, y) = symmetry.apply(geometry, x, y);
+                    1 << geometry.index(x, y)
+                })
+                .sum::<u16>()
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+/// Computer player for Notakto that plays perfectly by reading the value of the game in the misère quotient rather than searching.
+/// It doesn't implement Bot, since Notakto has its own state rather than a GameState.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MisereQuotient;
+
+/// Implementation for MisereQuotient containing the function to choose a move.
+impl MisereQuotient {
+    /// Creates a new Notakto player.
+    pub fn new() -> Self {
+        MisereQuotient
+    }
+
+    /// Chooses a move for the current player; one that leaves the opponent in a losing position if there is one,
+    /// otherwise one that kills as few boards as it can, leaving the opponent the most room to slip up.
+    pub fn choose_move(&self, state: &NotaktoState) -> Result<Move, Box<dyn std::error::Error>> {
+        let mut fallback: Option<(u8, Move)> = None;
+        for mv in state.legal_moves() {
+            let mut child: NotaktoState = state.clone();
+            child.apply_move(mv)?;
+            if Quotient::of_game(&child).is_losing() {
+                return Ok(mv);
+            }
+            // Otherwise rank the move; keeping every board alive is best, killing a board other than the last is next, and killing the last one (and losing) is worst.
+            let rank: u8 = match (child.status == GameStatus::Ongoing, child.is_dead(mv.z)) {
+                (false, _) => 2,
+                (true, true) => 1,
+                (true, false) => 0,
+            };
+            if fallback.is_none_or(|(best, _): (u8, Move)| rank < best) {
+                fallback = Some((rank, mv));
+            }
+        }
+        fallback
+            .map(|(_, mv): (u8, Move)| mv)
+            .ok_or_else(|| GameError::GameOver.into())
+    }
+}
@@ -0,0 +1,137 @@
+// Set module difficulty as public so that the cli and app can pick how strong the computer player is.
+pub mod difficulty;
+// Set module hint as public so that the cli and app can show the best move and evaluation.
+pub mod hint;
+// Set module mcts as public so that the cli and app can use the Monte Carlo Tree Search bot.
+pub mod mcts;
+// Set module minimax as public so that the cli and app can use the minimax bot.
+pub mod minimax;
+// Set module misere_quotient as public so that the notakto subcommand can use the perfect Notakto player.
+pub mod misere_quotient;
+// Set module solver as public so that the solve subcommand, the perfect computer player and hints can solve positions.
+pub mod solver;
+
+pub use difficulty::{Blundering, Difficulty};
+pub use hint::{hint, Hint};
+pub use mcts::Mcts;
+pub use minimax::Minimax;
+pub use misere_quotient::{MisereQuotient, Quotient};
+pub use solver::{CappedSolver, Solution, Solver};
+
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bot::ExternalBot,
+    logic::{GameState, GameStatus, Move, Player},
+    AiArgs, BoardArgs,
+};
+
+/// Mixed into the seed for the blunders of a weakened bot (the golden ratio constant SplitMix64 steps by), so that they don't repeat the random numbers of its search.
+const BLUNDER_SEED_MIX: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Trait for computer players that can choose a move for whoever's turn it is.
+/// Bots have to be Send so that the app can search on a thread of its own without freezing the window.
+pub trait Bot: Send {
+    /// Chooses a move for the current player of the given state.
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>>;
+}
+
+/// Enum to represent the kinds of built-in computer player.
+#[derive(Serialize, Deserialize, ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AiKind {
+    /// Exhaustive negamax search; perfect on small boards.
+    #[default]
+    Minimax,
+    /// Monte Carlo Tree Search; better suited to large boards.
+    Mcts,
+}
+
+/// Implementation for AiArgs containing a function to build the built-in bot they describe.
+impl AiArgs {
+    /// Builds the bot, limiting its search and adding blunders according to the difficulty.
+    pub fn bot(&self) -> Box<dyn Bot> {
+        let bot: Box<dyn Bot> = match self.ai {
+            // Searching to the end of the game is left to the solver, which remembers positions it has seen.
+            // It only solves positions small enough to finish in reasonable time, so that big boards don't leave the player waiting.
+            AiKind::Minimax => match self.difficulty.max_depth() {
+                Some(max_depth) => Box::new(Minimax::with_max_depth(Some(max_depth))),
+                None => Box::new(CappedSolver::new()),
+            },
+            AiKind::Mcts => {
+                // Only fall back on the difficulty's time budget if no budget was asked for.
+                let time_limit: Option<Duration> = match (self.ai_iterations, self.ai_time) {
+                    (None, None) => Some(self.difficulty.time_budget()),
+                    (_, ai_time) => ai_time.map(Duration::from_millis),
+                };
+                Box::new(Mcts::new(self.ai_iterations, time_limit, self.seed))
+            }
+        };
+        match self.difficulty.blunder_rate() {
+            0.0 => bot,
+            // The blunders are seeded apart from the bot's own search, so that the two don't draw the same random numbers.
+            blunder_rate => Box::new(Blundering::new(
+                bot,
+                blunder_rate,
+                self.seed.map(|seed: u64| seed ^ BLUNDER_SEED_MIX),
+            )),
+        }
+    }
+}
+
+/// Holds everything needed to recreate the computer player, so that it can be saved along with the game.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Opponent {
+    /// The side the computer plays.
+    pub side: Player,
+    #[serde(flatten)]
+    pub ai_args: AiArgs,
+    /// Command to start an external engine with, used instead of the built-in bot if given.
+    #[serde(default)]
+    pub engine: Option<String>,
+    /// Time in milliseconds to wait for each reply from an external engine.
+    #[serde(default = "default_engine_timeout")]
+    pub engine_timeout_ms: u64,
+}
+
+/// Function that returns the engine timeout used by saves made before it could be set.
+fn default_engine_timeout() -> u64 {
+    BoardArgs::default().engine_timeout
+}
+
+/// Implementation for Opponent containing functions to read it from the board arguments and build the bot it describes.
+impl Opponent {
+    /// Returns the computer player described by the board arguments, or None if not playing against the computer.
+    /// Asking for an external engine without a side puts it on O.
+    pub fn from_args(boardargs: &BoardArgs) -> Option<Self> {
+        let side: Player = match (boardargs.vs_ai, &boardargs.engine) {
+            (Some(side), _) => side,
+            (None, Some(_)) => Player::O,
+            (None, None) => return None,
+        };
+        Some(Opponent {
+            side,
+            ai_args: boardargs.ai_args,
+            engine: boardargs.engine.clone(),
+            engine_timeout_ms: boardargs.engine_timeout,
+        })
+    }
+
+    /// Returns true if the game is still going and it's the computer's turn.
+    pub fn is_turn(&self, state: &GameState) -> bool {
+        state.status == GameStatus::Ongoing && state.current_player == self.side
+    }
+
+    /// Builds the bot; either by starting the external engine or from the built-in computer players.
+    pub fn bot(&self) -> Result<Box<dyn Bot>, Box<dyn std::error::Error>> {
+        match &self.engine {
+            Some(command) => Ok(Box::new(ExternalBot::spawn(
+                command,
+                Duration::from_millis(self.engine_timeout_ms),
+            )?)),
+            None => Ok(self.ai_args.bot()),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use crate::logic::{
+    Cell, GameError, GameState, GameStatus, Geometry, Move, Player, Symmetry, Variant,
+};
+
+use super::{minimax::WIN_SCORE, Bot, Minimax};
+
+/// Window wide enough to hold every score, kept small so that widening it can't overflow.
+const INFINITY: i32 = WIN_SCORE + 1;
+
+/// Largest number of legal moves a position can have for it to be solved in reasonable time; any more and the search has to be cut short.
+pub const FULL_SEARCH_LIMIT: usize = 10;
+
+/// How many moves ahead a CappedSolver looks in positions too big to solve.
+const FALLBACK_DEPTH: i32 = 4;
+
+/// Enum to represent how a stored score relates to the position's true value, since alpha-beta cuts most searches short.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    /// The true value is at least the score.
+    Lower,
+    /// The true value is at most the score.
+    Upper,
+}
+
+/// Holds everything that tells one position apart from another; the canonical form of its board, who's to move and the game being played.
+/// It's stored with each entry in the table so that a different position whose hash happens to be the same isn't mistaken for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Position {
+    board: Vec<Cell>,
+    player: Player,
+    geometry: Geometry,
+    win_length: usize,
+    variant: Variant,
+}
+
+/// Holds what's known about a position from an earlier search; the position itself, and its score and how that relates to the true value.
+#[derive(Clone, Debug)]
+struct Entry {
+    position: Position,
+    score: i32,
+    bound: Bound,
+}
+
+/// Holds the game-theoretic value of a position; its score, every move that achieves it and how many positions were searched to find it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solution {
+    /// Score from the point of view of the player to move; WIN_SCORE minus the number of moves to the end for a win, the negative of that for a loss, and 0 for a draw.
+    pub score: i32,
+    /// Every move for the player to move that achieves the score, in board order. Empty if the game is over.
+    pub best_moves: Vec<Move>,
+    pub nodes: u64,
+}
+
+/// Implementation for Solution containing a function to describe the value.
+impl Solution {
+    /// Describes the value for the player to move, e.g. "X wins in 3 moves" or "draw with best play".
+    pub fn describe(&self, player: Player) -> String {
+        // Count only the winner's moves; the player to move makes the odd ones.
+        let plies: i32 = WIN_SCORE - self.score.abs();
+        let (winner, moves) = match self.score {
+            score if score > 0 => (player, (plies + 1) / 2),
+            score if score < 0 => (player.other(), plies / 2),
+            _ => return String::from("draw with best play"),
+        };
+        match moves {
+            1 => format!("{} wins in 1 move", winner),
+            moves => format!("{} wins in {} moves", winner, moves),
+        }
+    }
+}
+
+/// Bot that plays perfectly by solving the game, using negamax with alpha-beta pruning and a transposition table.
+/// Positions that are the same up to a rotation or reflection of the board share an entry in the table.
+/// The table is kept between moves (and games), so every search after the first is much quicker.
+#[derive(Clone, Debug, Default)]
+pub struct Solver {
+    /// Scores keyed by the canonical hash of the position, so that positions that are the same up to a rotation or reflection share an entry.
+    table: HashMap<u64, Entry>,
+    nodes: u64,
+}
+
+/// Implementation of 'Bot' for Solver that plays the first of the optimal moves.
+impl Bot for Solver {
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>> {
+        let solution: Solution = self.solve(state)?;
+        solution
+            .best_moves
+            .first()
+            .copied()
+            .ok_or_else(|| GameError::GameOver.into())
+    }
+}
+
+/// Implementation for Solver containing the search.
+impl Solver {
+    /// Creates a new solver with an empty transposition table.
+    pub fn new() -> Self {
+        Solver::default()
+    }
+
+    /// Solves the position, finding its value and every optimal move for the player to move.
+    pub fn solve(&mut self, state: &GameState) -> Result<Solution, Box<dyn std::error::Error>> {
+        self.nodes = 0;
+        let score: i32 = self.value(state, -INFINITY, INFINITY);
+        // Find the exact value of every move, so that every move matching the position's value can be listed.
+        // The table makes these searches cheap, since the first search has already been through the same positions.
+        let mut best_moves: Vec<Move> = Vec::new();
+        for mv in state.legal_moves() {
+            let mut child: GameState = state.clone();
+            child.apply_move(mv)?;
+            if step_back(-self.value(&child, -INFINITY, INFINITY)) == score {
+                best_moves.push(mv);
+            }
+        }
+        Ok(Solution {
+            score,
+            best_moves,
+            nodes: self.nodes,
+        })
+    }
+
+    /// Scores the state from the point of view of the player to move, exactly if the score is within alpha..beta.
+    /// Outside of the window the score is only a bound; at most alpha if it's below, at least beta if it's above.
+    fn value(&mut self, state: &GameState, mut alpha: i32, mut beta: i32) -> i32 {
+        self.nodes += 1;
+        match state.status {
+            // The turn isn't passed on once the game is over, so the current player is the one who just moved.
+            // The player to move here is their opponent, who has won only if the mover lost (as they do by completing a line in misère).
+            GameStatus::Won(winner) if winner == state.current_player => return -WIN_SCORE,
+            GameStatus::Won(_) => return WIN_SCORE,
+            GameStatus::Draw => return 0,
+            GameStatus::Ongoing => {}
+        }
+        // Use what's known about the position from earlier searches, which may settle it straight away.
+        // An entry under the same hash is only used if it's for the same position, since two positions can share a hash.
+        let symmetry: Symmetry = state.canonical_symmetry();
+        let key: u64 = state.symmetric_hash(symmetry);
+        let position: Position = Position {
+            board: state.symmetric_board(symmetry),
+            player: state.current_player,
+            geometry: state.geometry,
+            win_length: state.win_length,
+            variant: state.variant,
+        };
+        let known: Option<(i32, Bound)> = self
+            .table
+            .get(&key)
+            .filter(|entry: &&Entry| entry.position == position)
+            .map(|entry: &Entry| (entry.score, entry.bound));
+        if let Some((score, bound)) = known {
+            match bound {
+                Bound::Exact => return score,
+                Bound::Lower => alpha = alpha.max(score),
+                Bound::Upper => beta = beta.min(score),
+            }
+            if alpha >= beta {
+                return score;
+            }
+        }
+        let original_alpha: i32 = alpha;
+        let mut best: i32 = -INFINITY;
+        for mv in ordered_moves(state) {
+            let mut child: GameState = state.clone();
+            // Legal moves can't be rejected, so skip the move in the (impossible) case that it is.
+            if child.apply_move(mv).is_err() {
+                continue;
+            }
+            // A win one move further away is worth one less, so the child is searched in a window one wider on each side.
+            let child_alpha: i32 = (-beta - 1).max(-INFINITY);
+            let child_beta: i32 = (-alpha + 1).min(INFINITY);
+            let score: i32 = step_back(-self.value(&child, child_alpha, child_beta));
+            best = best.max(score);
+            alpha = alpha.max(score);
+            // If the other player already has a better option elsewhere they'll never allow this position.
+            if alpha >= beta {
+                break;
+            }
+        }
+        let bound: Bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        // A position that shares its hash with one already in the table takes its place.
+        self.table.insert(
+            key,
+            Entry {
+                position,
+                score: best,
+                bound,
+            },
+        );
+        best
+    }
+}
+
+/// Bot that plays perfectly once the position is small enough to solve, and searches FALLBACK_DEPTH moves ahead until then.
+/// Solving a big board (like 7x6 or Qubic) from the start would take far too long to wait for.
+#[derive(Clone, Debug, Default)]
+pub struct CappedSolver {
+    solver: Solver,
+}
+
+/// Implementation for CappedSolver containing a constructor.
+impl CappedSolver {
+    /// Creates a new capped solver with an empty transposition table.
+    pub fn new() -> Self {
+        CappedSolver::default()
+    }
+}
+
+/// Implementation of 'Bot' for CappedSolver that solves small positions and looks a few moves ahead in big ones.
+impl Bot for CappedSolver {
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>> {
+        if state.legal_moves().len() <= FULL_SEARCH_LIMIT {
+            self.solver.choose_move(state)
+        } else {
+            Minimax::with_max_depth(Some(FALLBACK_DEPTH)).choose_move(state)
+        }
+    }
+}
+
+/// Function that returns the legal moves closest to the centre of the board first, since they're usually the strongest and trying the strongest moves first prunes the most.
+fn ordered_moves(state: &GameState) -> Vec<Move> {
+    let Geometry {
+        width,
+        height,
+        depth,
+    } = state.geometry;
+    let mut moves: Vec<Move> = state.legal_moves();
+    // Distances are doubled so that the centre of an even sized board (between cells) stays a whole number.
+    moves.sort_by_key(|mv: &Move| {
+        (2 * mv.x).abs_diff(width - 1)
+            + (2 * mv.y).abs_diff(height - 1)
+            + (2 * mv.z).abs_diff(depth - 1)
+    });
+    moves
+}
+
+/// Function that moves a score one move further from the end of the game; wins and losses are worth a little less, draws stay the same.
+fn step_back(score: i32) -> i32 {
+    score - score.signum()
+}
@@ -1,74 +1,172 @@
 #![allow(non_snake_case)]
 
 use crate::{
-    logic::{GameState, GameStatus, Generate, Player},
-    BoardArgs,
+    ai::{hint, Bot, Difficulty, Hint, Opponent},
+    logic::{
+        quantum::QUANTUM_SIZE, ultimate::ULTIMATE_SIZE, GameState, GameStatus, Generate, Move,
+        Player, QuantumState, Rules, Symbol, UltimateState, Variant,
+    },
+    AiArgs, BoardArgs, BoardShapeArgs,
 };
 
+use clap::ValueEnum;
 use dioxus::prelude::*;
+use futures_channel::oneshot;
 
-// TODO: Add support for save/load game state as well as undo/redo
+// TODO: Add support for save/load game state
 // TODO: Add styling
-// TODO: Add support for user-defined board sizes
+// TODO: Let the board size be changed in the window (it can only be set on launch, e.g. `-l true board_size 4`)
 // TODO: Detailed comments and documentation like for the cli
 
 pub fn App() -> Element {
-    // Initialize game state
-    let board_args = BoardArgs {
-        size: Some(3), // Default to 3x3
-    };
+    // Initialize game state from the board arguments passed in on launch.
+    let board_args = use_hook(|| try_consume_context::<BoardArgs>().unwrap_or_default());
+    let variant_board_args = board_args.clone();
 
     // Signals for state management
     let mut game_state: Signal<GameState> =
         use_signal(|| GameState::new(&board_args).expect("Failed to initialize game state"));
-    let mut current_player = use_signal(|| Player::X);
     let mut game_status_message = use_signal(|| String::from("Game in progress...")); // Feedback signal
+    let mut difficulty: Signal<Difficulty> = use_signal(|| board_args.ai_args.difficulty); // Difficulty used when the computer is turned on
+    let mut opponent: Signal<Option<Opponent>> = use_signal(|| Opponent::from_args(&board_args)); // The computer player, if playing against one
+    let mut bot: Signal<Option<Box<dyn Bot>>> = use_signal(|| None); // The computer player's bot, built on its first move and kept for the rest of the game
+    let mut current_hint: Signal<Option<Hint>> = use_signal(|| None); // Best move and evaluation, cleared whenever the board changes
+    let mut ultimate: Signal<bool> = use_signal(|| false); // Whether Ultimate tic-tac-toe is being played instead
+    let mut quantum: Signal<bool> = use_signal(|| false); // Whether quantum tic-tac-toe is being played instead
+    let mut symbol: Signal<Symbol> = use_signal(|| Symbol::X); // Symbol to mark cells with when the rules let the player pick
+    let mut number: Signal<Option<u8>> = use_signal(|| None); // Number to play next when the rules have numbers, once one has been picked
+    let mut thinking: Signal<bool> = use_signal(|| false); // Whether the computer player is searching for a move on another thread
+
+    // Whenever it's the computer's turn let it choose and play a move.
+    // The search runs on a thread of its own so that the window keeps responding, with the controls that change the game turned off until it's done.
+    use_effect(move || {
+        let opponent = opponent
+            .read()
+            .clone()
+            .filter(|opponent| opponent.is_turn(&game_state.read()));
+        let Some(opponent) = opponent else {
+            return;
+        };
+        if *thinking.peek() {
+            return;
+        }
+        // Take the bot to the search thread, building it the first time it's needed so that it keeps its state (like a seeded random number generator or an engine process) between moves.
+        let mut searching_bot = match bot.write().take().map_or_else(|| opponent.bot(), Ok) {
+            Ok(searching_bot) => searching_bot,
+            Err(e) => {
+                game_status_message.set(format!("Error: {}", e));
+                return;
+            }
+        };
+        let position = game_state.peek().clone();
+        let (sender, receiver) = oneshot::channel();
+        std::thread::spawn(move || {
+            // Errors can't be sent between threads, so send their message instead.
+            let result = searching_bot
+                .choose_move(&position)
+                .map_err(|e| e.to_string());
+            let _ = sender.send((searching_bot, result));
+        });
+        thinking.set(true);
+        game_status_message.set(String::from("Computer is thinking..."));
+        // Play the move once the search thread hands the bot back along with it.
+        spawn(async move {
+            if let Ok((searching_bot, result)) = receiver.await {
+                bot.set(Some(searching_bot));
+                let mut state = game_state.write();
+                let result = result
+                    .map_err(|e| e.into())
+                    .and_then(|mv| state.apply_move(mv));
+                game_status_message.set(status_message(result));
+            }
+            thinking.set(false);
+        });
+    });
 
-    let board_size = board_args.size.unwrap_or(3); // Default to 3x3 if size is None
+    // Ultimate tic-tac-toe has its own layout, with a button to go back to the normal game
+    if *ultimate.read() {
+        return rsx! {
+            div {
+                h1 { "Ultimate Tic-Tac-Toe" }
+                button { onclick: move |_| ultimate.set(false), "Back to tic-tac-toe" }
+                UltimateBoard {}
+            }
+        };
+    }
+
+    // Quantum tic-tac-toe has its own layout too
+    if *quantum.read() {
+        return rsx! {
+            div {
+                h1 { "Quantum Tic-Tac-Toe" }
+                button { onclick: move |_| quantum.set(false), "Back to tic-tac-toe" }
+                QuantumBoard {}
+            }
+        };
+    }
 
     let state = game_state.read();
     let board = state.board.clone();
+    let board_geometry = state.geometry;
+    let board_width = board_geometry.width;
+    let current_player = state.current_player;
+    let has_opponent = opponent.read().is_some();
+    let variant = state.variant;
+    let chooses_symbols = variant.chooses_symbols();
+    let chosen_symbol = *symbol.read();
+    let uses_numbers = variant.uses_numbers();
+    let available_numbers = state.available_numbers(current_player);
+    let chosen_number = *number.read();
+    let roles = variant.describe_roles();
+    let is_thinking = *thinking.read();
 
     // Render the app
     rsx! {
         div {
             h1 { "Tic-Tac-Toe" }
+            button { onclick: move |_| ultimate.set(true), "Play Ultimate tic-tac-toe" }
+            button { onclick: move |_| quantum.set(true), "Play quantum tic-tac-toe" }
 
-            // Render the game board
-            div { style: "display: grid; grid-template-columns: repeat({board_size}, 50px);",
+            // Render the game board (the layers of a 3D board follow each other down the grid)
+            div { style: "display: grid; grid-template-columns: repeat({board_width}, 50px);",
                 {
                         board.iter().enumerate().map(|(index, cell)| {
+                            // Highlight cells that are part of a winning line
+                            let (x, y, z) = board_geometry.coords_3d(index);
+                            let highlight = if state.is_winning_cell(x, y, z) { "background-color: #f9e2af;" } else { "" };
+                            // Outline the hinted cell
+                            let hinted = current_hint.read().is_some_and(|hint| (hint.best_move.x, hint.best_move.y, hint.best_move.z) == (x, y, z));
+                            let outline = if hinted { "outline: 3px solid #89b4fa;" } else { "" };
+                            // Leave a gap above the first row of each layer after the first
+                            let gap = if y == 0 && z > 0 { "margin-top: 16px;" } else { "" };
                             rsx! {
                                 button {
-                                    style: "width: 50px; height: 50px; text-align: center; margin: 1; padding: 1; border: 1px solid black;",
+                                    style: "width: 50px; height: 50px; text-align: center; margin: 1; padding: 1; border: 1px solid black; {highlight} {outline} {gap}",
+                                    // The computer is to move while it's thinking, so cells can't be clicked until it's done
+                                    disabled: is_thinking,
                                     onclick: move |_| {
                                         let mut state = game_state.write(); // Mutably borrow game state
 
-                                        // If cell is empty, update it
-                                        if state.board[index] == ' ' {
-                                            state.board[index] = current_player.read().to_string().chars().next().unwrap();
-
-                                            // Check game status
-                                            match GameState::check_winner(&board_args, (*state).clone()) {
-                                                Ok(GameStatus::Won(winner)) => {
-                                                    game_status_message.set(format!("Player {} wins!", winner));
-                                                },
-                                                Ok(GameStatus::Draw) => {
-                                                    game_status_message.set(String::from("It's a draw!"));
-                                                },
-                                                Ok(GameStatus::Ongoing) => {
-                                                    // Switch player
-                                                    let next_player = match *current_player.read() {
-                                                        Player::X => Player::O,
-                                                        Player::O => Player::X,
-                                                    };
-                                                    current_player.set(next_player);
-                                                },
-                                                Err(e) => {
-                                                    game_status_message.set(format!("Error: {}", e));
-                                                },
-                                            }
+                                        // Make a move for the current player at the clicked cell.
+                                        let (x, y, z) = state.geometry.coords_3d(index);
+                                        let mut mv = Move::new(state.current_player, x, y).on_layer(z);
+                                        // Mark it with the chosen symbol if the rules let the player pick
+                                        if state.variant.chooses_symbols() {
+                                            mv = mv.with_symbol(*symbol.read());
+                                        }
+                                        // or play the picked number if the rules have numbers (without one the move is rejected)
+                                        if let Some(picked) = number.read().filter(|_| state.variant.uses_numbers()) {
+                                            mv = mv.with_number(picked);
                                         }
+
+                                        // Apply the move and check game status
+                                        let result = state.apply_move(mv);
+                                        // A number can only be played once, so pick a new one for the next move
+                                        if result.is_ok() {
+                                            number.set(None);
+                                        }
+                                        game_status_message.set(status_message(result));
+                                        current_hint.set(None);
                                     },
                                     "{cell}",
                                 },
@@ -76,11 +174,400 @@ pub fn App() -> Element {
                         })     }
             }
 
+            // Undo, redo and hint buttons, turned off while the computer is thinking
+            div {
+                button {
+                    disabled: is_thinking,
+                    onclick: move |_| {
+                        // Take back the last move if there is one
+                        let mut state = game_state.write();
+                        let result = state.undo().map(|_| GameStatus::Ongoing);
+                        // If that hands the turn back to the computer take back its move too
+                        if result.is_ok() && opponent.read().as_ref().is_some_and(|opponent| opponent.is_turn(&state)) {
+                            let _ = state.undo();
+                        }
+                        game_status_message.set(status_message(result));
+                        current_hint.set(None);
+                    },
+                    "Undo"
+                }
+                button {
+                    disabled: is_thinking,
+                    onclick: move |_| {
+                        // Replay the last undone move if there is one
+                        let mut state = game_state.write();
+                        let mut result = state.redo();
+                        // If that hands the turn to the computer replay its undone move too
+                        if result.is_ok() && opponent.read().as_ref().is_some_and(|opponent| opponent.is_turn(&state)) && !state.undone.is_empty() {
+                            result = state.redo();
+                        }
+                        game_status_message.set(status_message(result));
+                        current_hint.set(None);
+                    },
+                    "Redo"
+                }
+                button {
+                    disabled: is_thinking,
+                    onclick: move |_| {
+                        // Ask the engine for the best move and its evaluation of the position
+                        let hint = hint(&game_state.read());
+                        // Pick the hinted move's symbol (or number) too, so that clicking the outlined cell plays it
+                        if let Some(hint) = hint {
+                            symbol.set(hint.best_move.symbol());
+                            number.set(hint.best_move.number);
+                        }
+                        current_hint.set(hint);
+                    },
+                    "Hint"
+                }
+            }
+
+            // Toggle for playing against the computer (O unless launched with a different side)
+            div {
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: has_opponent,
+                        disabled: is_thinking,
+                        onchange: move |event: Event<FormData>| {
+                            // Build the computer player from the launch arguments, with the chosen difficulty
+                            let computer = Opponent::from_args(&BoardArgs {
+                                vs_ai: Some(board_args.vs_ai.unwrap_or(Player::O)),
+                                ai_args: AiArgs {
+                                    difficulty: *difficulty.read(),
+                                    ..board_args.ai_args
+                                },
+                                ..board_args.clone()
+                            });
+                            opponent.set(if event.checked() { computer } else { None });
+                            // The new opponent gets a bot of its own
+                            bot.set(None);
+                        },
+                    }
+                    "Play against the computer"
+                }
+                // Difficulty selector, which also changes the difficulty of a computer player that's already playing
+                select {
+                    disabled: is_thinking,
+                    onchange: move |event: Event<FormData>| {
+                        if let Ok(chosen) = Difficulty::from_str(&event.value(), true) {
+                            difficulty.set(chosen);
+                            if let Some(opponent) = opponent.write().as_mut() {
+                                opponent.ai_args.difficulty = chosen;
+                            }
+                            // Rebuild the bot with the new difficulty on its next move
+                            bot.set(None);
+                        }
+                    },
+                    for level in Difficulty::ALL {
+                        option {
+                            value: "{level}",
+                            selected: level == *difficulty.read(),
+                            "{level}"
+                        }
+                    }
+                }
+            }
+
+            // Rule variant selector, which starts a new game with the chosen rules (against the same opponent)
+            div {
+                "Rules: "
+                select {
+                    disabled: is_thinking,
+                    onchange: move |event: Event<FormData>| {
+                        if let Ok(chosen) = Variant::from_str(&event.value(), true) {
+                            let mut state = game_state.write();
+                            if let Ok(new_game) = GameState::new(&BoardArgs {
+                                shape: BoardShapeArgs {
+                                    variant: chosen,
+                                    ..variant_board_args.shape
+                                },
+                                ..variant_board_args.clone()
+                            }) {
+                                *state = new_game;
+                                // A new game gets a fresh bot
+                                bot.set(None);
+                                game_status_message.set(String::from("Game in progress..."));
+                                current_hint.set(None);
+                            }
+                        }
+                    },
+                    for rules in Variant::ALL {
+                        option {
+                            value: "{rules}",
+                            selected: rules == variant,
+                            "{rules}"
+                        }
+                    }
+                }
+            }
+
             // Display current player
-            div { "Current Player: {current_player.read()}" }
+            div { "Current Player: {current_player}" }
+
+            // Display the roles the players take, for rules that have them
+            if let Some(roles) = roles {
+                div { "Roles: {roles}" }
+            }
+
+            // Toggle for the symbol to mark with, when the rules let the player pick
+            if chooses_symbols {
+                div {
+                    button {
+                        onclick: move |_| {
+                            let other = symbol.read().other();
+                            symbol.set(other);
+                        },
+                        "Marking with {chosen_symbol} (click to switch)"
+                    }
+                }
+            }
+
+            // Picker for the number to play, when the rules have numbers, with the picked one highlighted
+            if uses_numbers {
+                div {
+                    "Number to play: "
+                    for available in available_numbers {
+                        button {
+                            style: if chosen_number == Some(available) { "background-color: #89b4fa;" } else { "" },
+                            onclick: move |_| number.set(Some(available)),
+                            "{available}"
+                        }
+                    }
+                }
+            }
+
+            // Display the evaluation from the last hint
+            if let Some(hint) = *current_hint.read() {
+                div { "Evaluation: {hint.evaluation()}" }
+            }
 
             // Display game status or feedback
             div { "Game Status: {game_status_message.read()}" }
         }
     }
 }
+
+/// Component for a game of Ultimate tic-tac-toe; the nine small boards laid out on the meta-board, with the boards that can be played in highlighted.
+fn UltimateBoard() -> Element {
+    let mut ultimate_state: Signal<UltimateState> =
+        use_signal(|| UltimateState::new().expect("Failed to initialize game state"));
+    let mut game_status_message = use_signal(|| String::from("Game in progress..."));
+
+    let state = ultimate_state.read();
+    let current_player = state.current_player;
+    let forced_board = match state.forced_board {
+        Some(index) => format!(
+            "board ({}, {})",
+            index % ULTIMATE_SIZE,
+            index / ULTIMATE_SIZE
+        ),
+        None => String::from("any board"),
+    };
+
+    rsx! {
+        // The meta-board, with a gap between the small boards
+        div { style: "display: grid; grid-template-columns: repeat({ULTIMATE_SIZE}, 160px); gap: 8px;",
+            for board_index in 0..ULTIMATE_SIZE * ULTIMATE_SIZE {
+                {
+                    // Highlight the boards that can be played in, and the boards in the line that won the game
+                    let (board_x, board_y) = (board_index % ULTIMATE_SIZE, board_index / ULTIMATE_SIZE);
+                    let background = if state.meta.is_winning_cell(board_x, board_y, 0) {
+                        "background-color: #f9e2af;"
+                    } else if state.is_playable_board(board_index) {
+                        "background-color: #89b4fa;"
+                    } else {
+                        ""
+                    };
+                    let small_board = &state.boards[board_index];
+                    rsx! {
+                        div { style: "display: grid; grid-template-columns: repeat({ULTIMATE_SIZE}, 50px); padding: 3px; {background}",
+                            for cell_index in 0..ULTIMATE_SIZE * ULTIMATE_SIZE {
+                                {
+                                    // Work out where the cell is on the whole grid
+                                    let (cell_x, cell_y) = (cell_index % ULTIMATE_SIZE, cell_index / ULTIMATE_SIZE);
+                                    let (x, y) = (board_x * ULTIMATE_SIZE + cell_x, board_y * ULTIMATE_SIZE + cell_y);
+                                    // Grey out finished boards, except for the line that won them
+                                    let faded = if small_board.is_winning_cell(cell_x, cell_y, 0) {
+                                        "font-weight: bold;"
+                                    } else if small_board.status != GameStatus::Ongoing {
+                                        "opacity: 0.4;"
+                                    } else {
+                                        ""
+                                    };
+                                    let cell = state.cell(x, y);
+                                    rsx! {
+                                        button {
+                                            style: "width: 50px; height: 50px; text-align: center; margin: 1; padding: 1; border: 1px solid black; {faded}",
+                                            onclick: move |_| {
+                                                // Make a move for the current player at the clicked cell
+                                                let mut state = ultimate_state.write();
+                                                let mv = Move::new(state.current_player, x, y);
+                                                game_status_message.set(status_message(state.apply_move(mv)));
+                                            },
+                                            "{cell}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Undo and new game buttons
+        div {
+            button {
+                onclick: move |_| {
+                    let result = ultimate_state.write().undo().map(|_| GameStatus::Ongoing);
+                    game_status_message.set(status_message(result));
+                },
+                "Undo"
+            }
+            button {
+                onclick: move |_| {
+                    if let Ok(new_game) = UltimateState::new() {
+                        ultimate_state.set(new_game);
+                        game_status_message.set(String::from("Game in progress..."));
+                    }
+                },
+                "New game"
+            }
+        }
+
+        // Display current player and where they have to play
+        if state.status == GameStatus::Ongoing {
+            div { "Current Player: {current_player}, playing in {forced_board}" }
+        }
+
+        // Display game status or feedback
+        div { "Game Status: {game_status_message.read()}" }
+    }
+}
+
+/// Component for a game of quantum tic-tac-toe; each cell lists its spooky marks or shows its classical mark.
+/// Clicking a cell picks the first half of a spooky mark and clicking a second cell plays it (clicking the first cell again puts it back); while a cycle is waiting to collapse, clicking one of its two highlighted cells collapses it there.
+fn QuantumBoard() -> Element {
+    let mut quantum_state: Signal<QuantumState> = use_signal(QuantumState::new);
+    let mut selected: Signal<Option<(usize, usize)>> = use_signal(|| None); // Cell picked for the first half of a spooky mark
+    let mut game_status_message = use_signal(|| String::from("Game in progress..."));
+
+    let state = quantum_state.read();
+    let current_player = state.current_player;
+    // Tell the current player what they have to do next
+    let instruction = match state.pending_collapse {
+        Some(mark) => format!("pick the cell {} collapses into", state.marks[mark]),
+        None if selected.read().is_some() => {
+            String::from("pick the second cell of your spooky mark")
+        }
+        None => String::from("pick the first cell of your spooky mark"),
+    };
+    // The cells the mark that closed a cycle could collapse into, if there is one
+    let collapse_cells = state
+        .pending_collapse
+        .map(|mark| state.marks[mark].cells.to_vec())
+        .unwrap_or_default();
+
+    rsx! {
+        div { style: "display: grid; grid-template-columns: repeat({QUANTUM_SIZE}, 100px);",
+            for index in 0..QUANTUM_SIZE * QUANTUM_SIZE {
+                {
+                    let (x, y) = state.geometry.coords(index);
+                    // Highlight the line that ended the game, the cells a collapse can go to and the cell picked for the first half of a spooky mark
+                    let highlight = if state.is_winning_cell(x, y) {
+                        "background-color: #f9e2af;"
+                    } else if collapse_cells.contains(&index) {
+                        "background-color: #cba6f7;"
+                    } else if *selected.read() == Some((x, y)) {
+                        "background-color: #89b4fa;"
+                    } else {
+                        ""
+                    };
+                    // Show a classical mark large, otherwise every spooky mark in the cell
+                    let (text, size) = match state.classical(index) {
+                        Some(mark) => (mark.to_string(), "font-size: 28px;"),
+                        None => (
+                            state.spooky(index).iter().map(|mark| mark.to_string()).collect::<Vec<String>>().join(" "),
+                            "font-size: 14px;",
+                        ),
+                    };
+                    rsx! {
+                        button {
+                            style: "width: 100px; height: 100px; text-align: center; margin: 1; padding: 1; border: 1px solid black; {highlight} {size}",
+                            onclick: move |_| {
+                                let mut state = quantum_state.write();
+                                let player = state.current_player;
+                                let result = if state.pending_collapse.is_some() {
+                                    // Collapse the cycle into the clicked cell
+                                    state.collapse(player, (x, y))
+                                } else if state.free_cells().len() == 1 {
+                                    // The last cell gets a classical mark
+                                    state.apply_move(player, (x, y), (x, y))
+                                } else {
+                                    let first = *selected.read();
+                                    match first {
+                                        // Clicking the first cell again puts it back
+                                        Some(first) if first == (x, y) => {
+                                            selected.set(None);
+                                            return;
+                                        }
+                                        // Otherwise play the spooky mark in both cells
+                                        Some(first) => {
+                                            selected.set(None);
+                                            state.apply_move(player, first, (x, y))
+                                        }
+                                        // Pick the first cell, as long as it doesn't have a classical mark
+                                        None => {
+                                            if state.classical(index).is_none() {
+                                                selected.set(Some((x, y)));
+                                            }
+                                            return;
+                                        }
+                                    }
+                                };
+                                // Add the points to the message once the game has been won
+                                let mut message = status_message(result);
+                                if let GameStatus::Won(_) = state.status {
+                                    message = format!("{} Points: X {}, O {}", message, state.points(Player::X), state.points(Player::O));
+                                }
+                                game_status_message.set(message);
+                            },
+                            "{text}"
+                        }
+                    }
+                }
+            }
+        }
+
+        // New game button
+        div {
+            button {
+                onclick: move |_| {
+                    quantum_state.set(QuantumState::new());
+                    selected.set(None);
+                    game_status_message.set(String::from("Game in progress..."));
+                },
+                "New game"
+            }
+        }
+
+        // Display current player and what they have to do
+        if state.status == GameStatus::Ongoing {
+            div { "Current Player: {current_player}, {instruction}" }
+        }
+
+        // Display game status or feedback
+        div { "Game Status: {game_status_message.read()}" }
+    }
+}
+
+/// Returns the feedback message for the result of a move, undo or redo.
+fn status_message(result: Result<GameStatus, Box<dyn std::error::Error>>) -> String {
+    match result {
+        Ok(GameStatus::Won(winner)) => format!("Player {} wins!", winner),
+        Ok(GameStatus::Draw) => String::from("It's a draw!"),
+        Ok(GameStatus::Ongoing) => String::from("Game in progress..."),
+        Err(e) => format!("Error: {}", e),
+    }
+}
@@ -0,0 +1,38 @@
+/// Errors that can occur while talking to an external engine.
+#[derive(Debug)]
+pub enum BotError {
+    EmptyCommand,
+    Timeout,
+    EngineExited,
+    IllegalMove(String),
+    InvalidReply(String),
+    InvalidCommand(String),
+    UnsupportedBoard,
+}
+
+/// Implementation of std::fmt::Display for BotError to display message based on error.
+impl std::fmt::Display for BotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BotError::EmptyCommand => write!(f, "No engine command was given."),
+            BotError::Timeout => write!(f, "The engine took too long to reply."),
+            BotError::EngineExited => write!(f, "The engine exited unexpectedly."),
+            BotError::IllegalMove(reply) => {
+                write!(f, "The engine played an illegal move: {}", reply)
+            }
+            BotError::InvalidReply(reply) => {
+                write!(f, "The engine sent an invalid reply: {}", reply)
+            }
+            BotError::InvalidCommand(command) => write!(f, "Invalid command: {}", command),
+            BotError::UnsupportedBoard => {
+                write!(
+                    f,
+                    "The bot protocol only describes flat boards, not 3D ones."
+                )
+            }
+        }
+    }
+}
+
+/// Implementation of std::error::Error for BotError so that BotError is seen as an error type.
+impl std::error::Error for BotError {}
@@ -0,0 +1,54 @@
+use std::io::{BufRead, Write};
+
+use crate::{
+    ai::Bot,
+    logic::{GameState, Geometry, Variant},
+    EngineArgs,
+};
+
+use super::protocol;
+
+/// Function that runs the built-in computer player as an engine, reading commands from stdin and writing replies to stdout until told to quit.
+/// Commands that can't be carried out are answered with an info line rather than ending the engine.
+pub fn run_engine(args: &EngineArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    let mut bot: Box<dyn Bot> = args.ai_args.bot();
+    // Play standard rules on a classic 3x3 board until told otherwise.
+    let mut game: (Geometry, usize, Variant) = (Geometry::new(3, 3), 3, Variant::Standard);
+    let mut state: Option<GameState> = None;
+
+    for line in stdin.lock().lines() {
+        let line: String = line?;
+        let (command, rest) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        match command {
+            "newgame" => match protocol::decode_newgame(rest) {
+                Ok(new_game) => {
+                    game = new_game;
+                    state = None;
+                    // Start the new game with a fresh bot.
+                    bot = args.ai_args.bot();
+                }
+                Err(e) => writeln!(stdout, "info error {}", e)?,
+            },
+            "isready" => writeln!(stdout, "readyok")?,
+            "position" => match protocol::decode_position(rest, game.0, game.1, game.2) {
+                Ok(position) => state = Some(position),
+                Err(e) => writeln!(stdout, "info error {}", e)?,
+            },
+            "go" => match state
+                .as_ref()
+                .map(|state: &GameState| bot.choose_move(state))
+            {
+                Some(Ok(mv)) => writeln!(stdout, "{}", protocol::encode_move(mv))?,
+                Some(Err(e)) => writeln!(stdout, "info error {}", e)?,
+                None => writeln!(stdout, "info error no position has been set")?,
+            },
+            "quit" => break,
+            // Unknown commands are ignored, as the protocol asks.
+            _ => {}
+        }
+        stdout.flush()?;
+    }
+    Ok(())
+}
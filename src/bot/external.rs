@@ -0,0 +1,138 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    ai::Bot,
+    logic::{GameState, Geometry, Move, Variant},
+};
+
+use super::{protocol, BotError};
+
+/// How long an engine is given to exit after being told to quit, before it's killed.
+const QUIT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How often to check whether an engine that's been told to quit has exited.
+const QUIT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Bot that runs an external engine as a subprocess and talks to it over stdin/stdout using the bot protocol.
+pub struct ExternalBot {
+    child: Child,
+    stdin: ChildStdin,
+    /// Lines the engine has written to stdout, read on a separate thread so that waiting for them can time out.
+    replies: Receiver<String>,
+    timeout: Duration,
+    /// Geometry, win length and variant of the game the engine was last told about, so it's only told about a new game when they change.
+    game: Option<(Geometry, usize, Variant)>,
+}
+
+/// Implementation for ExternalBot containing functions to start and talk to the engine.
+impl ExternalBot {
+    /// Starts the engine from a command line (split on whitespace), waiting at most timeout for each of its replies.
+    pub fn spawn(command: &str, timeout: Duration) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut words = command.split_whitespace();
+        let program: &str = words.next().ok_or(BotError::EmptyCommand)?;
+        // The engine's stderr is thrown away so that it can't draw over the board.
+        let mut child: Child = Command::new(program)
+            .args(words)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdin: ChildStdin = child.stdin.take().ok_or(BotError::EngineExited)?;
+        let stdout = child.stdout.take().ok_or(BotError::EngineExited)?;
+        // Forward every line the engine writes until it exits (which drops the sender and disconnects the channel).
+        let (sender, replies) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(ExternalBot {
+            child,
+            stdin,
+            replies,
+            timeout,
+            game: None,
+        })
+    }
+
+    /// Sends a single command to the engine.
+    fn send(&mut self, command: &str) -> Result<(), BotError> {
+        writeln!(self.stdin, "{}", command)
+            .and_then(|_| self.stdin.flush())
+            .map_err(|_| BotError::EngineExited)
+    }
+
+    /// Waits for a reply starting with keyword, skipping info lines and blank lines.
+    fn wait_for(&mut self, keyword: &str) -> Result<String, BotError> {
+        let deadline: Instant = Instant::now() + self.timeout;
+        loop {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            let line: String = match self.replies.recv_timeout(remaining) {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => return Err(BotError::Timeout),
+                Err(RecvTimeoutError::Disconnected) => return Err(BotError::EngineExited),
+            };
+            let line: &str = line.trim();
+            match line.split_whitespace().next() {
+                None | Some("info") => continue,
+                Some(word) if word == keyword => return Ok(line.to_string()),
+                Some(_) => return Err(BotError::InvalidReply(line.to_string())),
+            }
+        }
+    }
+}
+
+/// Implementation of 'Bot' for ExternalBot that sends the position to the engine and checks the move it replies with.
+impl Bot for ExternalBot {
+    fn choose_move(&mut self, state: &GameState) -> Result<Move, Box<dyn std::error::Error>> {
+        // The protocol has no way to describe layers, so engines can only play on flat boards.
+        if state.geometry.depth > 1 {
+            return Err(Box::new(BotError::UnsupportedBoard));
+        }
+        // Tell the engine about the game first if it hasn't seen one like it, and wait until it's ready.
+        let game: (Geometry, usize, Variant) = (state.geometry, state.win_length, state.variant);
+        if self.game != Some(game) {
+            self.send(&format!(
+                "newgame {}",
+                protocol::encode_newgame(state.geometry, state.win_length, state.variant)
+            ))?;
+            self.send("isready")?;
+            self.wait_for("readyok")?;
+            self.game = Some(game);
+        }
+        self.send(&format!("position {}", protocol::encode_position(state)))?;
+        self.send("go")?;
+        let reply: String = self.wait_for("bestmove")?;
+        let mv: Move = protocol::decode_move(&reply, state.current_player)?;
+        // Only accept the move if it's one of the legal moves (which name their symbol only if the player picks one, and their number only if they play one).
+        if !state.legal_moves().contains(&mv) {
+            return Err(Box::new(BotError::IllegalMove(reply)));
+        }
+        Ok(mv)
+    }
+}
+
+/// Implementation of Drop for ExternalBot that asks the engine to quit, and kills it if it hasn't within QUIT_TIMEOUT.
+impl Drop for ExternalBot {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        // Give the engine a moment to exit by itself, checking on it every QUIT_POLL_INTERVAL.
+        let deadline: Instant = Instant::now() + QUIT_TIMEOUT;
+        while let Ok(None) = self.child.try_wait() {
+            if Instant::now() >= deadline {
+                let _ = self.child.kill();
+                break;
+            }
+            thread::sleep(QUIT_POLL_INTERVAL);
+        }
+        let _ = self.child.wait();
+    }
+}
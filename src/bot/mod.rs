@@ -0,0 +1,12 @@
+// Set module bot_error as public so that errors from external engines can be matched on.
+pub mod bot_error;
+// Set module engine as public so that the built-in computer player can be run as an external engine.
+pub mod engine;
+// Set module external as public so that the cli and app can play against external engines.
+pub mod external;
+// Set module protocol as public so that the engine and the bot driving it agree on how to encode the game.
+pub mod protocol;
+
+pub use bot_error::BotError;
+pub use engine::run_engine;
+pub use external::ExternalBot;
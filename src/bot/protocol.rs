@@ -0,0 +1,170 @@
+//! A line-based protocol for driving bots over stdin/stdout, in the spirit of UCI.
+//!
+//! Commands sent to the engine:
+//! - `newgame <size> <k> [variant]` starts a new game, where size is either `N` for an NxN board or `WxH`, k is the win length,
+//!   and variant is the rule variant (e.g. `misere`), left out for standard rules.
+//! - `isready` asks the engine to reply `readyok` once it's ready for more commands.
+//! - `position <board> <side>` sets the position; the board is each row from top to bottom separated by `/`,
+//!   with `X`, `O`, a number from `1` to `9` or `.` for each cell, and side is the player to move (`X` or `O`).
+//! - `go` asks the engine for a move, which it answers with `bestmove <x> <y> [mark]` (zero-based column and row),
+//!   where mark is the `X` or `O` to mark the cell with in variants where the player picks (e.g. `wild`),
+//!   or the number to play in variants with numbers (`numerical`), and is left out otherwise.
+//! - `quit` tells the engine to exit.
+//!
+//! Engines may send `info <anything>` lines at any time, which are ignored, and should ignore commands they don't know.
+
+use crate::{
+    logic::{Cell, GameState, Generate, Geometry, Move, Player, Symbol, Variant},
+    BoardArgs, BoardShapeArgs,
+};
+
+use clap::ValueEnum;
+
+use super::BotError;
+
+/// Function that formats the board size for a newgame command ("3" for a 3x3 board, "7x6" for a 7 wide, 6 tall board).
+pub fn encode_size(geometry: Geometry) -> String {
+    if geometry.width == geometry.height {
+        geometry.width.to_string()
+    } else {
+        format!("{}x{}", geometry.width, geometry.height)
+    }
+}
+
+/// Function that formats the arguments of a newgame command.
+pub fn encode_newgame(geometry: Geometry, win_length: usize, variant: Variant) -> String {
+    match variant {
+        Variant::Standard => format!("{} {}", encode_size(geometry), win_length),
+        variant => format!("{} {} {}", encode_size(geometry), win_length, variant),
+    }
+}
+
+/// Function that reads the size, win length and variant (standard if left out) of a newgame command.
+pub fn decode_newgame(arguments: &str) -> Result<(Geometry, usize, Variant), BotError> {
+    let invalid = || BotError::InvalidCommand(format!("newgame {}", arguments));
+    let mut words = arguments.split_whitespace();
+    let geometry: Geometry = decode_size(words.next().ok_or_else(invalid)?)?;
+    let win_length: usize = words
+        .next()
+        .and_then(|win_length: &str| win_length.parse().ok())
+        .ok_or_else(invalid)?;
+    let variant: Variant = match words.next() {
+        Some(variant) => Variant::from_str(variant, true).map_err(|_| invalid())?,
+        None => Variant::Standard,
+    };
+    Ok((geometry, win_length, variant))
+}
+
+/// Function that reads the board size of a newgame command.
+pub fn decode_size(size: &str) -> Result<Geometry, BotError> {
+    let invalid = || BotError::InvalidCommand(format!("newgame {}", size));
+    match size.split_once('x') {
+        Some((width, height)) => Ok(Geometry::new(
+            width.parse().map_err(|_| invalid())?,
+            height.parse().map_err(|_| invalid())?,
+        )),
+        None => {
+            let size: usize = size.parse().map_err(|_| invalid())?;
+            Ok(Geometry::new(size, size))
+        }
+    }
+}
+
+/// Function that formats the board and player to move for a position command.
+pub fn encode_position(state: &GameState) -> String {
+    let rows: Vec<String> = state
+        .board
+        .chunks(state.geometry.width)
+        .map(|row: &[Cell]| {
+            row.iter()
+                .map(|cell: &Cell| match cell {
+                    Cell::Empty => '.',
+                    cell => char::from(*cell),
+                })
+                .collect()
+        })
+        .collect();
+    format!("{} {}", rows.join("/"), state.current_player)
+}
+
+/// Function that builds the game state described by a position command, for a game with the given geometry, win length and variant.
+pub fn decode_position(
+    position: &str,
+    geometry: Geometry,
+    win_length: usize,
+    variant: Variant,
+) -> Result<GameState, Box<dyn std::error::Error>> {
+    let invalid = || BotError::InvalidCommand(format!("position {}", position));
+    let (board, side) = position.split_once(' ').ok_or_else(invalid)?;
+    let mut state: GameState = GameState::new(&BoardArgs {
+        shape: BoardShapeArgs {
+            width: Some(geometry.width),
+            height: Some(geometry.height),
+            win_length: Some(win_length),
+            variant,
+            ..BoardShapeArgs::default()
+        },
+        ..BoardArgs::default()
+    })?;
+    state.board = board
+        .chars()
+        .filter(|&cell: &char| cell != '/')
+        .map(|cell: char| match cell {
+            '.' => Ok(Cell::Empty),
+            cell => Cell::try_from(cell).map_err(|_| invalid()),
+        })
+        .collect::<Result<Vec<Cell>, BotError>>()?;
+    if state.board.len() != geometry.len() {
+        return Err(Box::new(invalid()));
+    }
+    state.recount();
+    state.current_player = match side.trim() {
+        "X" => Player::X,
+        "O" => Player::O,
+        _ => return Err(Box::new(invalid())),
+    };
+    (state.status, state.winning_lines) = GameState::check_winner(&state)?;
+    Ok(state)
+}
+
+/// Function that formats a move for a bestmove reply, naming the symbol only if the move picks one, or the number if it plays one.
+pub fn encode_move(mv: Move) -> String {
+    match (mv.symbol, mv.number) {
+        (_, Some(number)) => format!("bestmove {} {} {}", mv.x, mv.y, number),
+        (Some(symbol), None) => format!("bestmove {} {} {}", mv.x, mv.y, symbol),
+        (None, None) => format!("bestmove {} {}", mv.x, mv.y),
+    }
+}
+
+/// Function that reads a bestmove reply into a move for player, with the symbol or number it names (if any).
+pub fn decode_move(reply: &str, player: Player) -> Result<Move, BotError> {
+    let invalid = || BotError::InvalidReply(reply.to_string());
+    let mut parts = reply.split_whitespace();
+    if parts.next() != Some("bestmove") {
+        return Err(invalid());
+    }
+    let x: usize = parts
+        .next()
+        .and_then(|x: &str| x.parse().ok())
+        .ok_or_else(invalid)?;
+    let y: usize = parts
+        .next()
+        .and_then(|y: &str| y.parse().ok())
+        .ok_or_else(invalid)?;
+    // The mark is a number if it parses as one, and a symbol otherwise.
+    let (symbol, number): (Option<Symbol>, Option<u8>) = match parts.next() {
+        Some(mark) => match mark.parse::<u8>() {
+            Ok(number) => (None, Some(number)),
+            Err(_) => (
+                Some(Symbol::from_str(mark, true).map_err(|_| invalid())?),
+                None,
+            ),
+        },
+        None => (None, None),
+    };
+    Ok(Move {
+        symbol,
+        number,
+        ..Move::new(player, x, y)
+    })
+}
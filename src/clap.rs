@@ -1,4 +1,12 @@
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ai::{AiKind, Difficulty},
+    logic::Variant,
+    tournament::{Entrant, Format},
+    Player,
+};
 
 #[derive(Subcommand, ValueEnum, Debug, Clone, PartialEq, Eq)]
 pub enum ShellCompletion {
@@ -13,10 +21,220 @@ pub struct GenerateArgs {
     pub shell: ShellCompletion,
 }
 
+#[derive(Args, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AiArgs {
+    #[arg(
+        long,
+        value_enum,
+        default_value = "minimax",
+        help = "Kind of computer player."
+    )]
+    // Saves made before the opponent kept its AiArgs named this kind (and the MCTS budgets iterations and time_limit_ms), so they're still read.
+    #[serde(alias = "kind")]
+    pub ai: AiKind,
+    #[arg(
+        long,
+        value_enum,
+        default_value = "perfect",
+        help = "How strong the computer player is."
+    )]
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[arg(
+        long,
+        help = "Number of iterations the MCTS computer player searches for."
+    )]
+    #[serde(alias = "iterations")]
+    pub ai_iterations: Option<u32>,
+    #[arg(
+        long,
+        help = "Time in milliseconds the MCTS computer player searches for."
+    )]
+    #[serde(alias = "time_limit_ms")]
+    pub ai_time: Option<u64>,
+    #[arg(long, help = "Seed for the computer player's random number generator.")]
+    pub seed: Option<u64>,
+}
+
+/// Implementation of Default for AiArgs that gives a perfect minimax player.
+impl Default for AiArgs {
+    fn default() -> Self {
+        AiArgs {
+            ai: AiKind::Minimax,
+            difficulty: Difficulty::Perfect,
+            ai_iterations: None,
+            ai_time: None,
+            seed: None,
+        }
+    }
+}
+
+/// The shape of the board and the rules played on it, shared by every subcommand that plays or searches a board.
 #[derive(Args, Debug, Clone, Copy, PartialEq, Eq)]
-pub struct BoardArgs {
-    #[arg(name = "NUMBER", help = "Size of board.", default_value = "3")]
+pub struct BoardShapeArgs {
+    #[arg(
+        name = "NUMBER",
+        help = "Size of board. Defaults to 3, or the variant's own board (7x6 with 4 in a row for gravity, 6x6 with 5 in a row for order-chaos, 3x3 for numerical)."
+    )]
     pub size: Option<usize>,
+    #[arg(long, help = "Width of board. Defaults to the size of board.")]
+    pub width: Option<usize>,
+    #[arg(long, help = "Height of board. Defaults to the size of board.")]
+    pub height: Option<usize>,
+    #[arg(
+        long,
+        help = "Number of layers of board, for 3D tic-tac-toe (--depth 4 on its own plays 4x4x4 Qubic). Defaults to 1."
+    )]
+    pub depth: Option<usize>,
+    #[arg(
+        long,
+        short = 'k',
+        help = "Number of marks in a row needed to win. Defaults to the shortest side of the board."
+    )]
+    pub win_length: Option<usize>,
+    #[arg(
+        long,
+        value_enum,
+        default_value = "standard",
+        help = "Rules to play with; in misere completing a line loses, in gravity marks drop to the bottom of their column, in wild each player picks X or O on every move, in order-chaos X (Order) needs exactly five in a row of either symbol on a 6x6 board and O (Chaos) wins by filling it, and in numerical X plays odd numbers and O even ones on a 3x3 board, where any line adding up to 15 wins."
+    )]
+    pub variant: Variant,
+}
+
+/// Implementation of Default for BoardShapeArgs that gives a classic 3x3 board.
+impl Default for BoardShapeArgs {
+    fn default() -> Self {
+        BoardShapeArgs {
+            size: None,
+            width: None,
+            height: None,
+            depth: None,
+            win_length: None,
+            variant: Variant::Standard,
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct BoardArgs {
+    #[command(flatten)]
+    pub shape: BoardShapeArgs,
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "o",
+        help = "Play against the computer, which plays the given side. Defaults to O."
+    )]
+    pub vs_ai: Option<Player>,
+    #[command(flatten)]
+    pub ai_args: AiArgs,
+    #[arg(
+        long,
+        value_name = "COMMAND",
+        help = "Play against an external engine speaking the bot protocol, which plays the side given by --vs-ai (O by default)."
+    )]
+    pub engine: Option<String>,
+    #[arg(
+        long,
+        default_value = "5000",
+        help = "Time in milliseconds to wait for each reply from an external engine."
+    )]
+    pub engine_timeout: u64,
+}
+
+/// Implementation of Default for BoardArgs that gives a classic 3x3 board.
+impl Default for BoardArgs {
+    fn default() -> Self {
+        BoardArgs {
+            shape: BoardShapeArgs::default(),
+            vs_ai: None,
+            ai_args: AiArgs::default(),
+            engine: None,
+            engine_timeout: 5000,
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EngineArgs {
+    #[command(flatten)]
+    pub ai_args: AiArgs,
+}
+
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct TournamentArgs {
+    #[arg(
+        long = "player",
+        short = 'p',
+        value_name = "PLAYER",
+        required = true,
+        help = "A player in the tournament: [name=]minimax[:difficulty], [name=]mcts[:difficulty] or [name=]engine:<command>. Give at least two."
+    )]
+    pub players: Vec<Entrant>,
+    #[arg(
+        long,
+        value_enum,
+        default_value = "round-robin",
+        help = "Who plays who; gauntlet pits the first player against each of the others."
+    )]
+    pub format: Format,
+    #[arg(
+        long,
+        default_value = "2",
+        help = "Number of games each pairing plays, alternating colours."
+    )]
+    pub games: usize,
+    #[command(flatten)]
+    pub shape: BoardShapeArgs,
+    #[arg(
+        long,
+        default_value = "5000",
+        help = "Time in milliseconds to wait for each reply from an external engine."
+    )]
+    pub engine_timeout: u64,
+    #[arg(
+        long,
+        help = "Seed for the built-in players' random number generators."
+    )]
+    pub seed: Option<u64>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        default_value = "tournament_results.jsonl",
+        help = "File to write every game to, one JSON record per line."
+    )]
+    pub results: String,
+}
+
+#[derive(Args, Debug, Clone, PartialEq, Eq)]
+pub struct SolveArgs {
+    #[command(flatten)]
+    pub shape: BoardShapeArgs,
+    #[arg(
+        long,
+        value_name = "POSITION",
+        help = "Position to solve from instead of an empty board; rows from top to bottom separated by '/' using X, O, the numbers 1 to 9 (for numerical) and '.' (e.g. \"X../.O./...\"), optionally followed by a space and the side to move. The rows set the size of the board."
+    )]
+    pub position: Option<String>,
+}
+
+#[derive(Args, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotaktoArgs {
+    #[arg(
+        name = "BOARDS",
+        default_value = "3",
+        help = "Number of boards to play on."
+    )]
+    pub boards: usize,
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "o",
+        help = "Play against the perfect computer player, which plays the given side. Defaults to O."
+    )]
+    pub vs_ai: Option<Player>,
 }
 
 #[derive(Subcommand, Debug, PartialEq, Eq)]
@@ -25,6 +243,36 @@ pub enum TicTacToeSubcommand {
     Generate(GenerateArgs),
     #[command(name = "board_size", about = "Size of board.")]
     Board(BoardArgs),
+    #[command(
+        name = "engine",
+        about = "Run the built-in computer player as an external engine speaking the bot protocol over stdin/stdout."
+    )]
+    Engine(EngineArgs),
+    #[command(
+        name = "tournament",
+        about = "Play a tournament between computer players and estimate their Elo ratings."
+    )]
+    Tournament(TournamentArgs),
+    #[command(
+        name = "solve",
+        about = "Solve a position, reporting the result with perfect play and the optimal moves."
+    )]
+    Solve(SolveArgs),
+    #[command(
+        name = "ultimate",
+        about = "Play Ultimate tic-tac-toe; nine boards, where the cell you play picks the board your opponent plays in next."
+    )]
+    Ultimate,
+    #[command(
+        name = "notakto",
+        about = "Play Notakto; both players mark X on one or more boards, a board dies with three in a row, and whoever kills the last board loses."
+    )]
+    Notakto(NotaktoArgs),
+    #[command(
+        name = "quantum",
+        about = "Play quantum tic-tac-toe; every move is a spooky mark in two cells, and closing a cycle of them lets your opponent pick how it collapses."
+    )]
+    Quantum,
 }
 
 /// Main entry for running the game.
@@ -2,5 +2,17 @@
 pub mod render_board;
 // Set module run_game as public so that main() can use it.
 pub mod run_game;
+// Set module run_notakto as public so that main() can use it.
+pub mod run_notakto;
+// Set module run_quantum as public so that main() can use it.
+pub mod run_quantum;
+// Set module run_solve as public so that main() can use it.
+pub mod run_solve;
+// Set module run_ultimate as public so that main() can use it.
+pub mod run_ultimate;
+// Set module saved_game as public so that run_game() can save the opponent next to the game.
+pub mod saved_game;
+// Set module terminal_game as public so that every runner can report moves and handle the keys they share in the same way.
+pub mod terminal_game;
 // Set the save file as a public constant to avoid redundancy.
 pub const SAVE_FILE: &str = "save_game.json";
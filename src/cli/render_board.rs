@@ -1,144 +1,507 @@
 use crossterm::{
     cursor::{self},
-    style::{Color, Print, SetForegroundColor},
+    style::{Color, Print, SetBackgroundColor, SetForegroundColor},
     terminal::{self, ClearType},
     ExecutableCommand,
 };
 use std::io::{self};
 
-use crate::GameState;
+use crate::logic::notakto::NOTAKTO_SIZE;
+use crate::logic::quantum::QUANTUM_SIZE;
+use crate::logic::ultimate::{ULTIMATE_CELLS, ULTIMATE_SIZE};
+use crate::logic::{Cell, NotaktoState, Player, QuantumMark, QuantumState, UltimateState};
+use crate::{GameState, GameStatus, Geometry};
 
-/// Function to render the game board in the terminal.
+/// Number of columns left blank between the layers of a 3D board.
+const LAYER_GAP: usize = 2;
+
+/// Function to render the game board in the terminal, with the layers of a 3D board side by side.
 pub fn render_board(
     state: &GameState,
-    board_size: usize,
-    cursor_pos: (usize, usize),
+    cursor_pos: (usize, usize, usize),
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let geometry: Geometry = state.geometry;
     let mut stdout: io::Stdout = io::stdout();
     stdout.execute(terminal::Clear(ClearType::All))?;
     stdout.execute(cursor::MoveTo(0, 0))?;
 
     // Render top border.
-    render_borders(&mut stdout, board_size, true)?;
+    render_borders(&mut stdout, geometry, true)?;
 
     // Render rows and middle borders.
-    render_rows(board_size, &mut stdout, state, cursor_pos)?;
+    render_rows(&mut stdout, state, cursor_pos)?;
 
     // Render bottom border.
-    render_borders(&mut stdout, board_size, false)?;
+    render_borders(&mut stdout, geometry, false)?;
 
     // Set cursor positions for cells.
+    move_to_cell(&mut stdout, geometry.width, cursor_pos)
+}
+
+/// Function to print a status line below the game board, leaving the cursor on the selected cell.
+pub fn render_status_line(
+    state: &GameState,
+    cursor_pos: (usize, usize, usize),
+    message: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stdout: io::Stdout = io::stdout();
+    // Move to the bottom of the board and print the message.
+    stdout.execute(cursor::MoveTo(1, (state.geometry.height * 2 + 1) as u16))?;
+    stdout.execute(Print(message))?;
+    // Move back to the selected cell.
+    move_to_cell(&mut stdout, state.geometry.width, cursor_pos)
+}
+
+/// Function to move the terminal cursor onto the cell at cursor_pos, on a board board_width cells wide; each layer is drawn board_width * 4 + 2 columns wide, plus the gap before the next one.
+fn move_to_cell(
+    stdout: &mut io::Stdout,
+    board_width: usize,
+    cursor_pos: (usize, usize, usize),
+) -> Result<(), Box<dyn std::error::Error>> {
+    let layer_offset: usize = cursor_pos.2 * (board_width * 4 + 2 + LAYER_GAP);
     stdout.execute(cursor::MoveTo(
-        (cursor_pos.0 * 4 + 3) as u16,
+        (layer_offset + cursor_pos.0 * 4 + 3) as u16,
         (cursor_pos.1 * 2 + 1) as u16,
     ))?;
     Ok(())
 }
 
-/// Function to render the borders of the game board.
+/// Function to render the borders of the game board, once for each layer.
 fn render_borders(
     stdout: &mut io::Stdout,
-    board_size: usize,
+    geometry: Geometry,
     is_top: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // If is_top is true
-    if is_top {
-        // print the top left corner,
-        stdout.execute(Print(" ┌"))?;
-        // and for each value in the range from 0 to board_size (not inclusive)
-        for x in 0..board_size {
+    let board_width: usize = geometry.width;
+    // Pick the corners and separators for the top border if is_top is true, otherwise for the bottom border.
+    let (left, separator, right) = if is_top {
+        (" ┌", "┬", "┐")
+    } else {
+        (" └", "┴", "┘")
+    };
+    // For each layer of the board
+    for z in 0..geometry.depth {
+        // leave a gap after the layer before it (if there is one),
+        if z > 0 {
+            stdout.execute(Print(" ".repeat(LAYER_GAP)))?;
+        }
+        // print the left corner,
+        stdout.execute(Print(left))?;
+        // and for each value in the range from 0 to board_width (not inclusive)
+        for x in 0..board_width {
             // print a horizontal border
             stdout.execute(Print("───"))?;
-            // and immediately after if the value is less than board_size - 1 print a horizontal border with an downward separator
-            if x < board_size - 1 {
-                stdout.execute(Print("┬"))?;
+            // and immediately after if the value is less than board_width - 1 print a horizontal border with a separator
+            if x < board_width - 1 {
+                stdout.execute(Print(separator))?;
+            }
+        }
+        // and finally print the right corner.
+        stdout.execute(Print(right))?;
+    }
+    // Move the cursor to the beginning of the next line.
+    stdout.execute(cursor::MoveToNextLine(0))?;
+    Ok(())
+}
+
+/// Function to render the rows of the game board, printing each row of every layer on the same line.
+fn render_rows(
+    stdout: &mut io::Stdout,
+    state: &GameState,
+    cursor_pos: (usize, usize, usize),
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Get the width, height and depth of the board.
+    let board_width: usize = state.geometry.width;
+    let board_height: usize = state.geometry.height;
+    let board_depth: usize = state.geometry.depth;
+    // For each value y in the range 0..board_height (not inclusive)
+    for y in 0..board_height {
+        // and each layer z of the board
+        for z in 0..board_depth {
+            // leave a gap after the layer before it (if there is one)
+            if z > 0 {
+                stdout.execute(Print(" ".repeat(LAYER_GAP)))?;
+            }
+            // and print a vertical border (prints the separator-less left-hand vertical borders)
+            stdout.execute(Print(" │"))?;
+            // and for each value x in range 0..board_width (not inclusive) (prints the separator-less middle vertical boarders)
+            for x in 0..board_width {
+                // let the index be the index of (x, y, z) on the board
+                let index = state.geometry.index_3d(x, y, z);
+                // and let each cell be the board at the index previously defined.
+                let cell = state.board[index];
+
+                // If the cell at (x, y, z) is part of a winning line highlight the char yellow
+                if state.is_winning_cell(x, y, z) {
+                    stdout.execute(SetForegroundColor(Color::Yellow))?;
+                }
+                // and if the the cell at (x, y, z) is equal to the current cursor position highlight the char blue
+                else if (x, y, z) == cursor_pos {
+                    stdout.execute(SetForegroundColor(Color::Blue))?;
+                }
+                // and if the cell is not empty highlight the char green
+                else if cell != Cell::Empty {
+                    stdout.execute(SetForegroundColor(Color::Green))?;
+                }
+                // otherwise highlight it white.
+                else {
+                    stdout.execute(SetForegroundColor(Color::White))?;
+                }
+                // Print each cell after going through the highlighting.
+                stdout.execute(Print(format!(" {} ", cell)))?;
+                // Highlight the rest (the borders) white.
+                stdout.execute(SetForegroundColor(Color::White))?;
+
+                // If the x value is less than the board_width - 1 print a vertical border after each cell in loop.
+                if x < board_width - 1 {
+                    stdout.execute(Print("│"))?;
+                }
             }
+            // Print a vertical border after all the x values are iterated (prints the separator-less right-hand vertical borders).
+            stdout.execute(Print("│"))?;
         }
-        // finally print the top right corner and move the cursor to the beginning of the next line.
-        stdout.execute(Print("┐"))?;
+        // Move to the beginning of the next line and immediately after
         stdout.execute(cursor::MoveToNextLine(0))?;
+        // if the y value is less than board_height - 1 (to make sure it does not print it at the bottom) print the border between the rows.
+        if y < board_height - 1 {
+            render_middle_borders(stdout, state.geometry)?;
+        }
     }
-    // If is_top is false
-    else {
-        // print the bottom left corner,
-        stdout.execute(Print(" └"))?;
-        // and for each value in the range from 0 to board_size (not inclusive)
-        for x in 0..board_size {
+    Ok(())
+}
+
+/// Function to render the border between two rows of the game board, once for each layer.
+fn render_middle_borders(
+    stdout: &mut io::Stdout,
+    geometry: Geometry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // For each layer z of the board
+    for z in 0..geometry.depth {
+        // leave a gap after the layer before it (if there is one),
+        if z > 0 {
+            stdout.execute(Print(" ".repeat(LAYER_GAP)))?;
+        }
+        // print a vertical border with a right-ward separator
+        stdout.execute(Print(" ├"))?;
+        // and immediately after for each value x in the range 0..geometry.width (not inclusive)
+        for x in 0..geometry.width {
             // print a horizontal border
             stdout.execute(Print("───"))?;
-            // and immediately after if the value is less than board_size - 1 print a horizontal border with an upward separator
-            if x < board_size - 1 {
-                stdout.execute(Print("┴"))?;
+            // and if x is less than geometry.width - 1 print a vertical border with both a left-ward and right-ward separator (to make sure it does not print it at the end)
+            if x < geometry.width - 1 {
+                stdout.execute(Print("┼"))?;
             }
         }
-        // finally print the bottom right corner and move the cursor to the beginning of the next line.
-        stdout.execute(Print("┘"))?;
-        stdout.execute(cursor::MoveToNextLine(0))?;
+        // and finally print a vertical border with a left-ward separator.
+        stdout.execute(Print("┤"))?;
     }
+    // Move the cursor to the beginning of the next line.
+    stdout.execute(cursor::MoveToNextLine(0))?;
     Ok(())
 }
 
-/// Function to render the rows of the game board.
-fn render_rows(
-    board_size: usize,
-    stdout: &mut io::Stdout,
-    state: &GameState,
+/// Function to render an Ultimate tic-tac-toe grid in the terminal, with double borders between the small boards and the boards that can be played in highlighted.
+pub fn render_ultimate_board(
+    state: &UltimateState,
     cursor_pos: (usize, usize),
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // For each value y in the range 0..board_size (not inclusive)
-    for y in 0..board_size {
-        // print a vertical border (prints the separator-less left-hand vertical borders)
-        stdout.execute(Print(" │"))?;
-        // and for each value x in range 0..board_size (not inclusive) (prints the separator-less middle vertical boarders)
-        for x in 0..board_size {
-            // let the index be y * board_size + x
-            let index = y * board_size + x;
-            // and let each cell be the board at the index previously defined.
-            let cell = state.board[index];
-
-            // If the the cell at (x, y) is equal to the current cursor position highlight the char blue
-            if (x, y) == cursor_pos {
+    let mut stdout: io::Stdout = io::stdout();
+    stdout.execute(terminal::Clear(ClearType::All))?;
+    stdout.execute(cursor::MoveTo(0, 0))?;
+
+    // Render top border, with double separators where the small boards meet.
+    render_ultimate_border(&mut stdout, (" ╔", "═══", "╦", "╤", "╗"))?;
+    for y in 0..ULTIMATE_CELLS {
+        stdout.execute(Print(" ║"))?;
+        for x in 0..ULTIMATE_CELLS {
+            let index: usize = UltimateState::board_index(x, y);
+            let board: &GameState = &state.boards[index];
+            let cell: Cell = state.cell(x, y);
+            // Cells of the boards that can be played in get a background, so the forced board stands out.
+            if state.is_playable_board(index) {
+                stdout.execute(SetBackgroundColor(Color::DarkBlue))?;
+            }
+            // If the small board is part of the line that won the game highlight the char yellow,
+            if state
+                .meta
+                .is_winning_cell(index % ULTIMATE_SIZE, index / ULTIMATE_SIZE, 0)
+            {
+                stdout.execute(SetForegroundColor(Color::Yellow))?;
+            }
+            // if the cell is the cursor's highlight it blue,
+            else if (x, y) == cursor_pos {
                 stdout.execute(SetForegroundColor(Color::Blue))?;
             }
-            // and if the cell is not empty highlight the char green
-            else if cell != ' ' {
-                stdout.execute(SetForegroundColor(Color::Green))?;
+            // if the cell is part of the line that won its small board highlight it magenta,
+            else if board.is_winning_cell(x % ULTIMATE_SIZE, y % ULTIMATE_SIZE, 0) {
+                stdout.execute(SetForegroundColor(Color::Magenta))?;
+            }
+            // grey out the rest of a finished small board,
+            else if board.status != GameStatus::Ongoing {
+                stdout.execute(SetForegroundColor(Color::DarkGrey))?;
             }
-            // otherwise highlight it white.
-            else {
+            // and otherwise highlight marks green and empty cells white.
+            else if cell != Cell::Empty {
+                stdout.execute(SetForegroundColor(Color::Green))?;
+            } else {
                 stdout.execute(SetForegroundColor(Color::White))?;
             }
-            // Print each cell after going through the highlighting.
             stdout.execute(Print(format!(" {} ", cell)))?;
-            // Highlight the rest (the borders) white.
+            stdout.execute(SetBackgroundColor(Color::Reset))?;
             stdout.execute(SetForegroundColor(Color::White))?;
+            // Separate the cells with a double border where one small board ends and the next begins.
+            if x < ULTIMATE_CELLS - 1 {
+                let is_edge: bool = (x + 1) % ULTIMATE_SIZE == 0;
+                stdout.execute(Print(if is_edge { "║" } else { "│" }))?;
+            }
+        }
+        stdout.execute(Print("║"))?;
+        stdout.execute(cursor::MoveToNextLine(0))?;
+        // Print the border below the row, doubled between rows of small boards.
+        if y < ULTIMATE_CELLS - 1 {
+            if (y + 1) % ULTIMATE_SIZE == 0 {
+                render_ultimate_border(&mut stdout, (" ╠", "═══", "╬", "╪", "╣"))?;
+            } else {
+                render_ultimate_border(&mut stdout, (" ╟", "───", "╫", "┼", "╢"))?;
+            }
+        }
+    }
+    // Render bottom border.
+    render_ultimate_border(&mut stdout, (" ╚", "═══", "╩", "╧", "╝"))?;
 
-            // If the x value is less than the board_size - 1 print a vertical border after each cell in loop.
-            if x < board_size - 1 {
-                stdout.execute(Print("│"))?;
+    // Say which board has to be played in below the grid.
+    if state.status == GameStatus::Ongoing {
+        let message: String = match state.forced_board {
+            Some(index) => format!(
+                "Player {} plays in board ({}, {}).",
+                state.current_player,
+                index % ULTIMATE_SIZE,
+                index / ULTIMATE_SIZE
+            ),
+            None => format!("Player {} plays in any board.", state.current_player),
+        };
+        stdout.execute(Print(format!(" {}", message)))?;
+    }
+
+    // Set cursor positions for cells.
+    move_to_cell(&mut stdout, ULTIMATE_CELLS, (cursor_pos.0, cursor_pos.1, 0))
+}
+
+/// Function to render one horizontal border of an Ultimate tic-tac-toe grid from its pieces; the left end, the line above or below a cell, the crossing between small boards, the crossing within a small board and the right end.
+fn render_ultimate_border(
+    stdout: &mut io::Stdout,
+    (left, line, board_cross, cell_cross, right): (&str, &str, &str, &str, &str),
+) -> Result<(), Box<dyn std::error::Error>> {
+    stdout.execute(Print(left))?;
+    for x in 0..ULTIMATE_CELLS {
+        stdout.execute(Print(line))?;
+        if x < ULTIMATE_CELLS - 1 {
+            let is_edge: bool = (x + 1) % ULTIMATE_SIZE == 0;
+            stdout.execute(Print(if is_edge { board_cross } else { cell_cross }))?;
+        }
+    }
+    stdout.execute(Print(right))?;
+    stdout.execute(cursor::MoveToNextLine(0))?;
+    Ok(())
+}
+
+/// Function to render a game of Notakto in the terminal, with the boards side by side like the layers of a 3D board and dead boards greyed out.
+pub fn render_notakto_board(
+    state: &NotaktoState,
+    cursor_pos: (usize, usize, usize),
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Lay the boards out as the layers of a single board, so the borders and cursor are placed the same way.
+    let geometry: Geometry =
+        Geometry::new(NOTAKTO_SIZE, NOTAKTO_SIZE).with_depth(state.boards.len());
+    let mut stdout: io::Stdout = io::stdout();
+    stdout.execute(terminal::Clear(ClearType::All))?;
+    stdout.execute(cursor::MoveTo(0, 0))?;
+
+    render_borders(&mut stdout, geometry, true)?;
+    for y in 0..NOTAKTO_SIZE {
+        for (z, board) in state.boards.iter().enumerate() {
+            if z > 0 {
+                stdout.execute(Print(" ".repeat(LAYER_GAP)))?;
+            }
+            stdout.execute(Print(" │"))?;
+            for x in 0..NOTAKTO_SIZE {
+                let cell: Cell = board.board[board.geometry.index(x, y)];
+                // If the cell is part of the line that killed its board highlight the char red,
+                if board.is_winning_cell(x, y, 0) {
+                    stdout.execute(SetForegroundColor(Color::Red))?;
+                }
+                // if the cell is the cursor's highlight it blue,
+                else if (x, y, z) == cursor_pos {
+                    stdout.execute(SetForegroundColor(Color::Blue))?;
+                }
+                // grey out the rest of a dead board,
+                else if state.is_dead(z) {
+                    stdout.execute(SetForegroundColor(Color::DarkGrey))?;
+                }
+                // and otherwise highlight marks green and empty cells white.
+                else if cell != Cell::Empty {
+                    stdout.execute(SetForegroundColor(Color::Green))?;
+                } else {
+                    stdout.execute(SetForegroundColor(Color::White))?;
+                }
+                stdout.execute(Print(format!(" {} ", cell)))?;
+                stdout.execute(SetForegroundColor(Color::White))?;
+                if x < NOTAKTO_SIZE - 1 {
+                    stdout.execute(Print("│"))?;
+                }
             }
+            stdout.execute(Print("│"))?;
         }
-        // Print a vertical border after all the x values are iterated (prints the separator-less right-hand vertical borders).
-        stdout.execute(Print("│"))?;
-        // // Move to the beginning of the next line and immediately after
         stdout.execute(cursor::MoveToNextLine(0))?;
-        // if the y value is less than board_size - 1 (to make sure it does not print it at the bottom)
-        if y < board_size - 1 {
-            // print a vertical border with a right-ward separator
-            stdout.execute(Print(" ├"))?;
-            // and immediately after for each value x in the range 0..board_size (not inclusive)
-            for x in 0..board_size {
-                // print a horizontal border
-                stdout.execute(Print("───"))?;
-                // and if x is less than board_size - 1 print a vertical border with both a left-ward and right-ward separator (to make sure it does not print it at the end)
-                if x < board_size - 1 {
-                    stdout.execute(Print("┼"))?;
+        if y < NOTAKTO_SIZE - 1 {
+            render_middle_borders(&mut stdout, geometry)?;
+        }
+    }
+    render_borders(&mut stdout, geometry, false)?;
+
+    // Say whose turn it is below the boards.
+    if state.status == GameStatus::Ongoing {
+        stdout.execute(Print(format!(
+            " Player {} to move; whoever kills the last board loses.",
+            state.current_player
+        )))?;
+    }
+
+    // Set cursor positions for cells.
+    move_to_cell(&mut stdout, NOTAKTO_SIZE, cursor_pos)
+}
+
+/// Number of columns inside each cell of a quantum board, room for three spooky marks side by side.
+const QUANTUM_CELL_WIDTH: usize = 9;
+
+/// Function to render a game of quantum tic-tac-toe in the terminal.
+/// Each cell is three rows of three slots, with the spooky mark played on move n in slot n (so marks keep their place as the cell fills up) and a classical mark in the middle.
+/// The cell picked for the first half of a spooky mark (if any) has a blue background, and the two cells a pending collapse can go to a magenta one.
+pub fn render_quantum_board(
+    state: &QuantumState,
+    cursor_pos: (usize, usize),
+    selected: Option<(usize, usize)>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stdout: io::Stdout = io::stdout();
+    stdout.execute(terminal::Clear(ClearType::All))?;
+    stdout.execute(cursor::MoveTo(0, 0))?;
+
+    // The cells the mark that closed a cycle could collapse into, if there is one.
+    let collapse_cells: Vec<usize> = state
+        .pending_collapse
+        .map(|mark: usize| state.marks[mark].cells.to_vec())
+        .unwrap_or_default();
+
+    render_quantum_border(&mut stdout, (" ┌", "┬", "┐"))?;
+    for y in 0..QUANTUM_SIZE {
+        // Each cell takes up three rows of the terminal.
+        for row in 0..3 {
+            stdout.execute(Print(" │"))?;
+            for x in 0..QUANTUM_SIZE {
+                let index: usize = state.geometry.index(x, y);
+                if collapse_cells.contains(&index) {
+                    stdout.execute(SetBackgroundColor(Color::DarkMagenta))?;
+                } else if selected == Some((x, y)) {
+                    stdout.execute(SetBackgroundColor(Color::DarkBlue))?;
                 }
+                let text: String = match state.classical(index) {
+                    // A classical mark is shown in the middle of its cell, yellow if it's part of a line that ended the game and green otherwise.
+                    Some(mark) => {
+                        let colour: Color = if state.is_winning_cell(x, y) {
+                            Color::Yellow
+                        } else {
+                            Color::Green
+                        };
+                        stdout.execute(SetForegroundColor(colour))?;
+                        match row {
+                            1 => format!("{:^QUANTUM_CELL_WIDTH$}", mark.to_string()),
+                            _ => " ".repeat(QUANTUM_CELL_WIDTH),
+                        }
+                    }
+                    // Spooky marks are shown in their slots, blue in the cursor's cell and white otherwise.
+                    None => {
+                        let colour: Color = if (x, y) == cursor_pos {
+                            Color::Blue
+                        } else {
+                            Color::White
+                        };
+                        stdout.execute(SetForegroundColor(colour))?;
+                        let spooky: Vec<&QuantumMark> = state.spooky(index);
+                        (row * 3 + 1..=row * 3 + 3)
+                            .map(|turn: usize| {
+                                spooky
+                                    .iter()
+                                    .find(|mark: &&&QuantumMark| mark.turn == turn)
+                                    .map_or(String::from("   "), |mark: &&QuantumMark| {
+                                        format!("{:>3}", mark.to_string())
+                                    })
+                            })
+                            .collect()
+                    }
+                };
+                stdout.execute(Print(text))?;
+                stdout.execute(SetBackgroundColor(Color::Reset))?;
+                stdout.execute(SetForegroundColor(Color::White))?;
+                stdout.execute(Print("│"))?;
             }
-            // and finally print a vertical border with a left-ward separator.
-            stdout.execute(Print("┤"))?;
             stdout.execute(cursor::MoveToNextLine(0))?;
         }
+        if y < QUANTUM_SIZE - 1 {
+            render_quantum_border(&mut stdout, (" ├", "┼", "┤"))?;
+        }
+    }
+    render_quantum_border(&mut stdout, (" └", "┴", "┘"))?;
+
+    // Say what the current player has to do below the board.
+    if state.status == GameStatus::Ongoing {
+        let player: Player = state.current_player;
+        let message: String = match state.pending_collapse {
+            Some(mark) => format!(
+                "Player {}: pick the cell {} collapses into.",
+                player, state.marks[mark]
+            ),
+            None if state.free_cells().len() == 1 => {
+                format!("Player {}: play the last cell.", player)
+            }
+            None => {
+                let half: &str = if selected.is_some() {
+                    "second"
+                } else {
+                    "first"
+                };
+                format!(
+                    "Player {}: pick the {} cell for {}{}.",
+                    player,
+                    half,
+                    player,
+                    state.marks.len() + 1
+                )
+            }
+        };
+        stdout.execute(Print(format!(" {}", message)))?;
+    }
+
+    // Set the cursor on the middle row of the cursor's cell.
+    stdout.execute(cursor::MoveTo(
+        (cursor_pos.0 * (QUANTUM_CELL_WIDTH + 1) + 2 + QUANTUM_CELL_WIDTH / 2) as u16,
+        (cursor_pos.1 * 4 + 2) as u16,
+    ))?;
+    Ok(())
+}
+
+/// Function to render one horizontal border of a quantum board from its left end, the separator between cells and its right end.
+fn render_quantum_border(
+    stdout: &mut io::Stdout,
+    (left, separator, right): (&str, &str, &str),
+) -> Result<(), Box<dyn std::error::Error>> {
+    stdout.execute(Print(left))?;
+    for x in 0..QUANTUM_SIZE {
+        stdout.execute(Print("─".repeat(QUANTUM_CELL_WIDTH)))?;
+        if x < QUANTUM_SIZE - 1 {
+            stdout.execute(Print(separator))?;
+        }
     }
+    stdout.execute(Print(right))?;
+    stdout.execute(cursor::MoveToNextLine(0))?;
     Ok(())
 }
@@ -10,143 +10,196 @@ use std::{
     time::Duration,
 };
 
-use crate::cli::render_board::render_board;
-use crate::{BoardArgs, GameState, GameStatus, Generate, Player};
+use crate::ai::{hint, Bot, Opponent};
+use crate::cli::render_board::{render_board, render_status_line};
+use crate::logic::{GameError, Move, Rules, Symbol};
+use crate::{BoardArgs, Geometry, Player};
 
-use super::SAVE_FILE;
+use super::{
+    saved_game::SavedGame,
+    terminal_game::{handle_shared_key, report_move, TerminalGame},
+    SAVE_FILE,
+};
 
 /// Function to run the game in the terminal.
 pub fn run_game(boardargs: &BoardArgs) -> Result<(), Box<dyn std::error::Error>> {
     // Enable raw mode to filter inputs and outputs.
     terminal::enable_raw_mode()?;
 
-    let board_size: usize = boardargs.size.unwrap_or(3);
-    let mut state: GameState = GameState::new(boardargs)?;
+    // The game, along with the computer player it's against (if any).
+    let mut game: SavedGame = SavedGame::new(boardargs)?;
 
-    let mut cursor_pos: (usize, usize) = (0, 0);
+    // The cursor's (x, y) position and the layer z it's on (always 0 on a flat board).
+    let mut cursor_pos: (usize, usize, usize) = (0, 0, 0);
 
     let mut stdout = io::stdout();
 
     // Prompt user to load game from a file or not.
-    load_screen(&mut stdout, &mut state, board_size, cursor_pos)?;
+    load_screen(&mut stdout, &mut game, cursor_pos)?;
+
+    // Set up the computer player (if playing against one) from the game, so that a loaded game keeps its opponent.
+    let mut bot: Option<Box<dyn Bot>> = game.opponent.as_ref().map(Opponent::bot).transpose()?;
 
     // Set cursor style to underscore for the sake of sanity.
     stdout.execute(SetCursorStyle::SteadyUnderScore)?;
 
+    // Holds a message (like a hint) to show below the board until the next key is pressed.
+    let mut status_line: Option<String> = None;
+
+    // The symbol to mark cells with, if the rules let the player pick (switched with Tab).
+    let mut symbol: Symbol = Symbol::from(game.state.current_player);
+
     // Repeats code until either a win or a draw causes a break.
     loop {
+        // Get the dimensions of the board (these can change if a game is loaded).
+        let geometry: Geometry = game.state.geometry;
+        // Keep the cursor on the board, in case a loaded game has fewer layers.
+        cursor_pos.2 = cursor_pos.2.min(geometry.depth - 1);
+        // If marks fall, keep the cursor on the cell a mark dropped in its column would land in.
+        let drops_marks: bool = game.state.variant.drops_marks();
+        if drops_marks {
+            (cursor_pos.0, cursor_pos.1) =
+                game.state
+                    .landing_cell(cursor_pos.0, cursor_pos.1, cursor_pos.2);
+        }
         // render board
-        render_board(&state, board_size, cursor_pos)?;
+        render_board(&game.state, cursor_pos)?;
+        // If it's the computer's turn let it choose and play a move instead of reading keys.
+        if let Some(bot) = bot.as_mut().filter(|_| game.is_computer_turn()) {
+            let result = bot
+                .choose_move(&game.state)
+                .and_then(|mv: Move| game.state.apply_move(mv));
+            // Report the outcome of the move and end the game if it's over.
+            if report_move(&mut stdout, &game, cursor_pos, result, false)? {
+                break;
+            }
+            continue;
+        }
+        // Show the status line (if there is one) below the board, or the symbol being marked with if the player picks it.
+        if let Some(message) = status_line.take() {
+            render_status_line(&game.state, cursor_pos, &message)?;
+        } else {
+            // Otherwise show the roles the players take, the symbol being marked with and the numbers left to play, for rules that have them.
+            let mut message: Vec<String> = Vec::new();
+            if let Some(roles) = game.state.variant.describe_roles() {
+                message.push(format!("{}; {} to move", roles, game.state.current_player));
+            }
+            if game.state.variant.chooses_symbols() {
+                message.push(format!("Marking with {} (Tab to switch)", symbol));
+            }
+            if game.state.variant.uses_numbers() {
+                let numbers: Vec<String> = game
+                    .state
+                    .available_numbers(game.state.current_player)
+                    .iter()
+                    .map(u8::to_string)
+                    .collect();
+                message.push(format!(
+                    "{} has {} left (type a number to play it)",
+                    game.state.current_player,
+                    numbers.join(" ")
+                ));
+            }
+            if !message.is_empty() {
+                render_status_line(&game.state, cursor_pos, &message.join(". "))?;
+            }
+        }
         // Read key events and map each to vary cursor positions.
         if let Event::Key(key_event) = event::read()? {
             match key_event.code {
-                KeyCode::Up | KeyCode::Char('k') => {
-                    // Subtracts 1 from y value while it is greater than 0.
-                    if cursor_pos.1 > 0 {
-                        cursor_pos.1 -= 1;
-                    }
+                // Subtracts 1 from y value while it is greater than 0 (unless marks fall, where only the column can be picked).
+                KeyCode::Up | KeyCode::Char('k') if cursor_pos.1 > 0 && !drops_marks => {
+                    cursor_pos.1 -= 1;
+                }
+                // Adds 1 to y value while it is less than the board's height -1 (unless marks fall).
+                KeyCode::Down | KeyCode::Char('j')
+                    if cursor_pos.1 < geometry.height - 1 && !drops_marks =>
+                {
+                    cursor_pos.1 += 1;
+                }
+                // Subtracts 1 from x value while it is greater than 0.
+                KeyCode::Left | KeyCode::Char('h') if cursor_pos.0 > 0 => {
+                    cursor_pos.0 -= 1;
+                }
+                // Adds 1 to x value while it is less than the board's width -1.
+                KeyCode::Right | KeyCode::Char('l') if cursor_pos.0 < geometry.width - 1 => {
+                    cursor_pos.0 += 1;
+                }
+                // Moves to the previous layer of a 3D board while the z value is greater than 0.
+                KeyCode::Char('[') if cursor_pos.2 > 0 => {
+                    cursor_pos.2 -= 1;
+                }
+                // Moves to the next layer of a 3D board while the z value is less than the board's depth -1.
+                KeyCode::Char(']') if cursor_pos.2 < geometry.depth - 1 => {
+                    cursor_pos.2 += 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    // Adds 1 to y value while it is less than board_size -1.
-                    if cursor_pos.1 < board_size - 1 {
-                        cursor_pos.1 += 1;
+                // Typing a number plays it at the cursor, if the rules have numbers.
+                KeyCode::Char(digit @ '1'..='9') if game.state.variant.uses_numbers() => {
+                    let mv: Move = Move::new(game.state.current_player, cursor_pos.0, cursor_pos.1)
+                        .with_number(digit as u8 - b'0');
+                    let result = game.state.apply_move(mv);
+                    // Report the outcome of the move and end the game if it's over.
+                    if report_move(&mut stdout, &game, cursor_pos, result, true)? {
+                        break;
                     }
                 }
-                KeyCode::Left | KeyCode::Char('h') => {
-                    // Subtracts 1 from x value while it is greater than 0.
-                    if cursor_pos.0 > 0 {
-                        cursor_pos.0 -= 1;
+                // Enter can't say which number to play, so it does nothing if the rules have numbers.
+                KeyCode::Enter | KeyCode::Char('w') if !game.state.variant.uses_numbers() => {
+                    // Make a move for the current player at the cursor's (x, y) position on its layer (or drop it down the cursor's column if marks fall).
+                    let mut mv: Move =
+                        Move::new(game.state.current_player, cursor_pos.0, cursor_pos.1)
+                            .on_layer(cursor_pos.2);
+                    // Mark it with the chosen symbol if the rules let the player pick.
+                    if game.state.variant.chooses_symbols() {
+                        mv = mv.with_symbol(symbol);
+                    }
+                    let result = game.state.apply_move(mv);
+                    // Report the outcome of the move and end the game if it's over.
+                    if report_move(&mut stdout, &game, cursor_pos, result, true)? {
+                        break;
                     }
                 }
-                KeyCode::Right | KeyCode::Char('l') => {
-                    // Adds 1 to x value while it is less than board_size -1.
-                    if cursor_pos.0 < board_size - 1 {
-                        cursor_pos.0 += 1;
+                // Switches the symbol to mark with, if the rules let the player pick.
+                KeyCode::Tab if game.state.variant.chooses_symbols() => {
+                    symbol = symbol.other();
+                }
+                KeyCode::Char('r') => {
+                    // Replay the last move that was undone, moving the cursor to the cell it was played in.
+                    if let Some(mv) = game.state.undone.last() {
+                        cursor_pos = (mv.x, mv.y, mv.z);
+                    }
+                    let mut result = game.state.redo();
+                    // If that hands the turn to the computer, replay its undone move too (if it has one).
+                    if result.is_ok() && game.is_computer_turn() && !game.state.undone.is_empty() {
+                        result = game.state.redo();
+                    }
+                    // Report the outcome of the move and end the game if it's over.
+                    if report_move(&mut stdout, &game, cursor_pos, result, true)? {
+                        break;
                     }
                 }
-                KeyCode::Enter | KeyCode::Char('w') => {
-                    // Sets the board_index to the y value times the board_size plus the x value.
-                    let board_index = cursor_pos.1 * board_size + cursor_pos.0;
-                    // If the char in the board at board_index is empty then set that char to the current player as a char.
-                    if state.board[board_index] == ' ' {
-                        state.board[board_index] = state
-                            .current_player
-                            .to_string()
-                            .chars()
-                            .next()
-                            .ok_or("Failed to get player character")?;
-                        // Save state to save file (saving the new char).
-                        state.save_game(SAVE_FILE)?;
-
-                        // Matches the value of GameState::check_winner() to find the GameStatus and act accordingly
-                        match GameState::check_winner(boardargs, state.clone()) {
-                            // If the returned value is Ok() then match the value of status.
-                            Ok(status) => match status {
-                                // If the status returned is Won, then end the game and return the winner.
-                                GameStatus::Won(winner) => {
-                                    state.status = GameStatus::Won(winner);
-
-                                    render_board(&state, board_size, cursor_pos)?;
-
-                                    // Move to the bottom of the board.
-                                    stdout
-                                        .execute(cursor::MoveTo(1, (board_size * 2 + 1) as u16))?;
-                                    stdout.execute(SetBackgroundColor(Color::Green))?;
-                                    stdout.execute(Print(format!("Player {} wins!", winner)))?;
-                                    stdout.execute(SetBackgroundColor(Color::Reset))?;
-                                    stdout.execute(cursor::MoveToNextLine(0))?;
-
-                                    // Save state to save file (saving the GameStatus).
-                                    state.save_game(SAVE_FILE)?;
-                                    break;
-                                }
-                                // If the status returned is Draw, then end the game and report it.
-                                GameStatus::Draw => {
-                                    state.status = GameStatus::Draw;
-
-                                    render_board(&state, board_size, cursor_pos)?;
-
-                                    // Move to the bottom of the board.
-                                    stdout
-                                        .execute(cursor::MoveTo(1, (board_size * 2 + 1) as u16))?;
-                                    stdout.execute(SetBackgroundColor(Color::DarkGrey))?;
-                                    stdout.execute(Print(format!("It's a draw!")))?;
-                                    stdout.execute(SetBackgroundColor(Color::Reset))?;
-                                    stdout.execute(cursor::MoveToNextLine(0))?;
-
-                                    // Save state to save file (saving the GameStatus).
-                                    state.save_game(SAVE_FILE)?;
-                                    break;
-                                }
-                                // If the status returned is Ongoing, then continue the game.
-                                GameStatus::Ongoing => {}
-                            },
-                            // If the value returned is Err(), move to the bottom of the board and report the error.
-                            Err(e) => {
-                                stdout.execute(cursor::MoveTo(1, (board_size * 2 + 1) as u16))?;
-                                stdout.execute(SetBackgroundColor(Color::Red))?;
-                                stdout.execute(Print(format!("Error: {}", e)))?;
-                                stdout.execute(SetBackgroundColor(Color::Reset))?;
-                                stdout.execute(cursor::MoveToNextLine(0))?;
-                                break;
-                            }
-                        }
-                        // Go to the next player by using match to swap the values.
-                        state.current_player = match state.current_player {
-                            Player::X => Player::O,
-                            Player::O => Player::X,
-                        };
-
-                        // Save state to save file (saving the current_player).
-                        state.save_game(SAVE_FILE)?;
+                KeyCode::Char('?') => {
+                    // Move the cursor to the engine's best move and show its evaluation of the position.
+                    if let Some(hint) = hint(&game.state) {
+                        cursor_pos = (hint.best_move.x, hint.best_move.y, hint.best_move.z);
+                        symbol = hint.best_move.symbol();
+                        status_line = Some(match hint.best_move.number {
+                            // Name the number to play, since the cursor can't show it.
+                            Some(number) => format!("Hint: play {}. {}", number, hint.evaluation()),
+                            None => format!("Hint: {}", hint.evaluation()),
+                        });
                     }
                 }
-                // If esc is hit break out of the loop and exit the game.
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    break;
+                // Take back moves or quit with the keys every game shares, and do nothing for any other key :3.
+                key => {
+                    let computer: Option<Player> = game
+                        .opponent
+                        .as_ref()
+                        .map(|opponent: &Opponent| opponent.side);
+                    if handle_shared_key(&mut game, key, &mut cursor_pos, computer)? {
+                        break;
+                    }
                 }
-                // If any other key is hit do nothing :3.
-                _ => {}
             }
         }
     }
@@ -155,12 +208,44 @@ pub fn run_game(boardargs: &BoardArgs) -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+/// Implementation of 'TerminalGame' for SavedGame that draws the board, saves the game after every change, and lets a person carry on after picking an occupied cell, a number that's been played or a redo with nothing to redo.
+impl TerminalGame for SavedGame {
+    type Cursor = (usize, usize, usize);
+    fn render(&self, cursor_pos: Self::Cursor) -> Result<(), Box<dyn std::error::Error>> {
+        render_board(&self.state, cursor_pos)
+    }
+    fn bottom(&self) -> u16 {
+        (self.state.geometry.height * 2 + 1) as u16
+    }
+    fn current_player(&self) -> Player {
+        self.state.current_player
+    }
+    fn is_recoverable(&self, error: &GameError) -> bool {
+        matches!(
+            error,
+            GameError::CellOccupied | GameError::InvalidNumber | GameError::NothingToRedo
+        )
+    }
+    fn undo(&mut self) -> Result<Self::Cursor, Box<dyn std::error::Error>> {
+        let mv: Move = self.state.undo()?;
+        Ok((mv.x, mv.y, mv.z))
+    }
+    fn describe_win(&self, winner: Player) -> String {
+        match self.state.variant.role(winner) {
+            Some(role) => format!("Player {} ({}) wins!", winner, role),
+            None => format!("Player {} wins!", winner),
+        }
+    }
+    fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.save_game(SAVE_FILE)
+    }
+}
+
 /// Function that prompts the user as to whether or not to load a game from the save file.
 fn load_screen(
     stdout: &mut io::Stdout,
-    state: &mut GameState,
-    board_size: usize,
-    cursor_pos: (usize, usize),
+    game: &mut SavedGame,
+    cursor_pos: (usize, usize, usize),
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Repeat so that if an invalid char is inputted the user can try again.
     loop {
@@ -170,11 +255,14 @@ fn load_screen(
         if let Event::Key(key_event) = event::read()? {
             match key_event.code {
                 // If key 'y' is pressed and result type is Ok load the game from save file if not and result is Err(e) return e.
-                KeyCode::Char('y') => match GameState::load_game(SAVE_FILE) {
-                    Ok(loaded_state) => {
-                        *state = loaded_state;
-                        render_board(&*state, board_size, cursor_pos)?;
-                        stdout.execute(cursor::MoveTo(0, (board_size * 2 + 1) as u16))?;
+                KeyCode::Char('y') => match SavedGame::load_game(SAVE_FILE) {
+                    Ok(loaded_game) => {
+                        *game = loaded_game;
+                        render_board(&game.state, cursor_pos)?;
+                        stdout.execute(cursor::MoveTo(
+                            0,
+                            (game.state.geometry.height * 2 + 1) as u16,
+                        ))?;
                         stdout.execute(SetBackgroundColor(Color::Green))?;
                         stdout.execute(Print("Game loaded successfully."))?;
                         stdout.execute(SetBackgroundColor(Color::Reset))?;
@@ -0,0 +1,120 @@
+use crossterm::{
+    cursor::SetCursorStyle,
+    event::{self, Event, KeyCode},
+    terminal::{self},
+    ExecutableCommand,
+};
+use std::io::{self};
+
+use crate::ai::MisereQuotient;
+use crate::cli::render_board::render_notakto_board;
+use crate::cli::terminal_game::{handle_shared_key, report_move, TerminalGame};
+use crate::logic::notakto::NOTAKTO_SIZE;
+use crate::logic::{GameError, Move, NotaktoState};
+use crate::{NotaktoArgs, Player};
+
+/// Function to run a game of Notakto in the terminal, against the perfect computer player if asked for.
+pub fn run_notakto(args: &NotaktoArgs) -> Result<(), Box<dyn std::error::Error>> {
+    // Set up the game before taking over the terminal, so that an invalid number of boards is reported on a terminal that still works.
+    let mut state: NotaktoState = NotaktoState::new(args.boards)?;
+    let bot: MisereQuotient = MisereQuotient::new();
+
+    // Enable raw mode to filter inputs and outputs.
+    terminal::enable_raw_mode()?;
+
+    // The cursor's (x, y) position and the board z it's on, starting in the middle of the first board.
+    let mut cursor_pos: (usize, usize, usize) = (NOTAKTO_SIZE / 2, NOTAKTO_SIZE / 2, 0);
+
+    let mut stdout = io::stdout();
+
+    // Set cursor style to underscore for the sake of sanity.
+    stdout.execute(SetCursorStyle::SteadyUnderScore)?;
+
+    // Repeats code until a player kills the last board.
+    loop {
+        render_notakto_board(&state, cursor_pos)?;
+        // If it's the computer's turn let it choose and play a move instead of reading keys.
+        if args.vs_ai == Some(state.current_player) {
+            let result = bot
+                .choose_move(&state)
+                .and_then(|mv: Move| state.apply_move(mv));
+            // Report the outcome of the move and end the game if it's over.
+            if report_move(&mut stdout, &state, cursor_pos, result, false)? {
+                break;
+            }
+            continue;
+        }
+        // Read key events and map each to vary cursor positions.
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Up | KeyCode::Char('k') if cursor_pos.1 > 0 => {
+                    cursor_pos.1 -= 1;
+                }
+                KeyCode::Down | KeyCode::Char('j') if cursor_pos.1 < NOTAKTO_SIZE - 1 => {
+                    cursor_pos.1 += 1;
+                }
+                KeyCode::Left | KeyCode::Char('h') if cursor_pos.0 > 0 => {
+                    cursor_pos.0 -= 1;
+                }
+                KeyCode::Right | KeyCode::Char('l') if cursor_pos.0 < NOTAKTO_SIZE - 1 => {
+                    cursor_pos.0 += 1;
+                }
+                // Moves to the previous board while the z value is greater than 0.
+                KeyCode::Char('[') if cursor_pos.2 > 0 => {
+                    cursor_pos.2 -= 1;
+                }
+                // Moves to the next board while the z value is less than the number of boards -1.
+                KeyCode::Char(']') if cursor_pos.2 < state.boards.len() - 1 => {
+                    cursor_pos.2 += 1;
+                }
+                KeyCode::Enter | KeyCode::Char('w') => {
+                    // Put an X for the current player at the cursor's (x, y) position on its board.
+                    let mv: Move = Move::new(state.current_player, cursor_pos.0, cursor_pos.1)
+                        .on_layer(cursor_pos.2);
+                    let result = state.apply_move(mv);
+                    // Report the outcome of the move and end the game if it's over.
+                    if report_move(&mut stdout, &state, cursor_pos, result, true)? {
+                        break;
+                    }
+                }
+                // Take back moves or quit with the keys every game shares, and do nothing for any other key.
+                key => {
+                    if handle_shared_key(&mut state, key, &mut cursor_pos, args.vs_ai)? {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    // Once the loop is broken disable raw mode and return the terminal to its original state.
+    terminal::disable_raw_mode()?;
+    Ok(())
+}
+
+/// Implementation of 'TerminalGame' for NotaktoState that draws the boards, names the loser along with the winner, and lets a person carry on after picking a dead board or an occupied cell.
+impl TerminalGame for NotaktoState {
+    type Cursor = (usize, usize, usize);
+    fn render(&self, cursor_pos: Self::Cursor) -> Result<(), Box<dyn std::error::Error>> {
+        render_notakto_board(self, cursor_pos)
+    }
+    fn bottom(&self) -> u16 {
+        (NOTAKTO_SIZE * 2 + 1) as u16
+    }
+    fn current_player(&self) -> Player {
+        self.current_player
+    }
+    fn is_recoverable(&self, error: &GameError) -> bool {
+        matches!(error, GameError::CellOccupied | GameError::BoardFinished)
+    }
+    fn undo(&mut self) -> Result<Self::Cursor, Box<dyn std::error::Error>> {
+        let mv: Move = NotaktoState::undo(self)?;
+        Ok((mv.x, mv.y, mv.z))
+    }
+    fn describe_win(&self, winner: Player) -> String {
+        format!(
+            "Player {} killed the last board, so player {} wins!",
+            winner.other(),
+            winner
+        )
+    }
+}
@@ -0,0 +1,122 @@
+use crossterm::{
+    cursor::SetCursorStyle,
+    event::{self, Event, KeyCode},
+    terminal::{self},
+    ExecutableCommand,
+};
+use std::io::{self};
+
+use crate::cli::render_board::render_quantum_board;
+use crate::cli::terminal_game::{handle_shared_key, report_move, TerminalGame};
+use crate::logic::quantum::QUANTUM_SIZE;
+use crate::logic::{GameError, QuantumState};
+use crate::Player;
+
+/// Function to run a game of quantum tic-tac-toe in the terminal.
+/// A spooky mark is played by picking its first cell and then its second (picking the first cell again puts it back), and a collapse by picking one of the two highlighted cells.
+pub fn run_quantum() -> Result<(), Box<dyn std::error::Error>> {
+    // Enable raw mode to filter inputs and outputs.
+    terminal::enable_raw_mode()?;
+
+    let mut state: QuantumState = QuantumState::new();
+
+    // Start the cursor in the centre cell.
+    let mut cursor_pos: (usize, usize) = (QUANTUM_SIZE / 2, QUANTUM_SIZE / 2);
+
+    // The cell picked for the first half of the spooky mark being played, if any.
+    let mut selected: Option<(usize, usize)> = None;
+
+    let mut stdout = io::stdout();
+
+    // Set cursor style to underscore for the sake of sanity.
+    stdout.execute(SetCursorStyle::SteadyUnderScore)?;
+
+    // Repeats code until either a win or a draw causes a break.
+    loop {
+        render_quantum_board(&state, cursor_pos, selected)?;
+        // Read key events and map each to vary cursor positions.
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Up | KeyCode::Char('k') if cursor_pos.1 > 0 => {
+                    cursor_pos.1 -= 1;
+                }
+                KeyCode::Down | KeyCode::Char('j') if cursor_pos.1 < QUANTUM_SIZE - 1 => {
+                    cursor_pos.1 += 1;
+                }
+                KeyCode::Left | KeyCode::Char('h') if cursor_pos.0 > 0 => {
+                    cursor_pos.0 -= 1;
+                }
+                KeyCode::Right | KeyCode::Char('l') if cursor_pos.0 < QUANTUM_SIZE - 1 => {
+                    cursor_pos.0 += 1;
+                }
+                KeyCode::Enter | KeyCode::Char('w') => {
+                    let player: Player = state.current_player;
+                    let result = if state.pending_collapse.is_some() {
+                        // Collapse the cycle into the cursor's cell.
+                        state.collapse(player, cursor_pos)
+                    } else if state.free_cells().len() == 1 {
+                        // The last cell gets a classical mark.
+                        state.apply_move(player, cursor_pos, cursor_pos)
+                    } else {
+                        match selected.take() {
+                            // Picking the first cell again puts it back.
+                            Some(first) if first == cursor_pos => continue,
+                            // Otherwise play the spooky mark in both cells.
+                            Some(first) => state.apply_move(player, first, cursor_pos),
+                            // Pick the first cell, as long as it doesn't have a classical mark.
+                            None => {
+                                let index: usize = state.geometry.index(cursor_pos.0, cursor_pos.1);
+                                if state.classical(index).is_none() {
+                                    selected = Some(cursor_pos);
+                                }
+                                continue;
+                            }
+                        }
+                    };
+                    // Report the outcome of the move and end the game if it's over.
+                    if report_move(&mut stdout, &state, cursor_pos, result, true)? {
+                        break;
+                    }
+                }
+                // Take back moves or quit with the keys every game shares, and do nothing for any other key.
+                key => {
+                    if handle_shared_key(&mut state, key, &mut cursor_pos, None)? {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    // Once the loop is broken disable raw mode and return the terminal to its original state.
+    terminal::disable_raw_mode()?;
+    Ok(())
+}
+
+/// Implementation of 'TerminalGame' for QuantumState that draws the board, gives the points each player scored along with the winner, and lets a person carry on after picking a classical mark or collapsing into the wrong cell.
+/// Spooky marks can't be taken back, since a collapse can settle marks from many moves before.
+impl TerminalGame for QuantumState {
+    type Cursor = (usize, usize);
+    fn render(&self, cursor_pos: Self::Cursor) -> Result<(), Box<dyn std::error::Error>> {
+        render_quantum_board(self, cursor_pos, None)
+    }
+    fn bottom(&self) -> u16 {
+        (QUANTUM_SIZE * 4 + 1) as u16
+    }
+    fn current_player(&self) -> Player {
+        self.current_player
+    }
+    fn is_recoverable(&self, error: &GameError) -> bool {
+        matches!(error, GameError::CellOccupied | GameError::InvalidCollapse)
+    }
+    fn undo(&mut self) -> Result<Self::Cursor, Box<dyn std::error::Error>> {
+        Err(Box::new(GameError::NothingToUndo))
+    }
+    fn describe_win(&self, winner: Player) -> String {
+        format!(
+            "Player {} wins! Points: X {}, O {}",
+            winner,
+            self.points(Player::X),
+            self.points(Player::O)
+        )
+    }
+}
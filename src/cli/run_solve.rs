@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    ai::{Solution, Solver},
+    bot::protocol,
+    logic::{Cell, Move, Player, Variant},
+    BoardArgs, GameState, GameStatus, Generate, Geometry, SolveArgs,
+};
+
+/// Function that solves the position given on the command line and prints its value, the optimal moves and how much searching it took.
+pub fn run_solve(args: &SolveArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let state: GameState = match &args.position {
+        Some(position) => read_position(position, args.shape.win_length, args.shape.variant)?,
+        None => GameState::new(&BoardArgs {
+            shape: args.shape,
+            ..BoardArgs::default()
+        })?,
+    };
+    // A 3D board is named by all three of its sides, and its moves by the layer they're on too.
+    let is_3d: bool = state.geometry.depth > 1;
+    let size: String = if is_3d {
+        format!(
+            "{}x{}x{}",
+            state.geometry.width, state.geometry.height, state.geometry.depth
+        )
+    } else {
+        format!("{}x{}", state.geometry.width, state.geometry.height)
+    };
+    println!(
+        "Solving a {} {} board with {} in a row, {} to move...",
+        size, state.variant, state.win_length, state.current_player
+    );
+    // A finished game has nothing left to solve.
+    match state.status {
+        GameStatus::Won(winner) => {
+            println!("The game is already over; {} has won.", winner);
+            return Ok(());
+        }
+        GameStatus::Draw => {
+            println!("The game is already over; it's a draw.");
+            return Ok(());
+        }
+        GameStatus::Ongoing => {}
+    }
+
+    let start: Instant = Instant::now();
+    let solution: Solution = Solver::new().solve(&state)?;
+    let elapsed: Duration = start.elapsed();
+    let best_moves: Vec<String> = solution
+        .best_moves
+        .iter()
+        .map(|mv: &Move| {
+            let cell: String = if is_3d {
+                format!("({}, {}, {})", mv.x, mv.y, mv.z)
+            } else {
+                format!("({}, {})", mv.x, mv.y)
+            };
+            // Name the number too when the move plays one, or the symbol when the player picks it.
+            match (mv.symbol, mv.number) {
+                (_, Some(number)) => format!("{}{}", number, cell),
+                (Some(symbol), None) => format!("{}{}", symbol, cell),
+                (None, None) => cell,
+            }
+        })
+        .collect();
+    println!("Result: {}", solution.describe(state.current_player));
+    println!(
+        "Optimal moves {}: {}",
+        if is_3d { "(x, y, z)" } else { "(x, y)" },
+        best_moves.join(" ")
+    );
+    println!("Nodes searched: {}", solution.nodes);
+    println!("Time: {:.3}s", elapsed.as_secs_f64());
+    Ok(())
+}
+
+/// Function that reads a position written as rows separated by '/', optionally followed by the side to move.
+/// The rows set the size of the board, and without a side to move it's whoever has made fewer moves (X if it's even).
+fn read_position(
+    position: &str,
+    win_length: Option<usize>,
+    variant: Variant,
+) -> Result<GameState, Box<dyn std::error::Error>> {
+    let (board, side) = position
+        .trim()
+        .split_once(' ')
+        .unwrap_or((position.trim(), ""));
+    let rows: Vec<&str> = board.split('/').collect();
+    let geometry: Geometry = Geometry::new(rows[0].chars().count(), rows.len());
+    let side: String = match side.trim() {
+        // Players take turns, so X has made as many moves as O when the number of marks is even (whichever symbols or numbers they were).
+        "" => {
+            let marks: usize = board
+                .chars()
+                .filter(|&cell: &char| {
+                    matches!(
+                        Cell::try_from(cell),
+                        Ok(Cell::Occupied(_) | Cell::Number(_))
+                    )
+                })
+                .count();
+            if marks.is_multiple_of(2) {
+                Player::X.to_string()
+            } else {
+                Player::O.to_string()
+            }
+        }
+        side => side.to_string(),
+    };
+    let win_length: usize = win_length.unwrap_or(geometry.width.min(geometry.height));
+    protocol::decode_position(
+        &format!("{} {}", board, side),
+        geometry,
+        win_length,
+        variant,
+    )
+}
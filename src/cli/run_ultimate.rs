@@ -0,0 +1,93 @@
+use crossterm::{
+    cursor::SetCursorStyle,
+    event::{self, Event, KeyCode},
+    terminal::{self},
+    ExecutableCommand,
+};
+use std::io::{self};
+
+use crate::cli::render_board::render_ultimate_board;
+use crate::cli::terminal_game::{handle_shared_key, report_move, TerminalGame};
+use crate::logic::ultimate::ULTIMATE_CELLS;
+use crate::logic::{GameError, Move, UltimateState};
+use crate::Player;
+
+/// Function to run a game of Ultimate tic-tac-toe in the terminal.
+pub fn run_ultimate() -> Result<(), Box<dyn std::error::Error>> {
+    // Enable raw mode to filter inputs and outputs.
+    terminal::enable_raw_mode()?;
+
+    let mut state: UltimateState = UltimateState::new()?;
+
+    // Start the cursor in the middle of the centre board.
+    let mut cursor_pos: (usize, usize) = (ULTIMATE_CELLS / 2, ULTIMATE_CELLS / 2);
+
+    let mut stdout = io::stdout();
+
+    // Set cursor style to underscore for the sake of sanity.
+    stdout.execute(SetCursorStyle::SteadyUnderScore)?;
+
+    // Repeats code until either a win or a draw causes a break.
+    loop {
+        render_ultimate_board(&state, cursor_pos)?;
+        // Read key events and map each to vary cursor positions, which move over the whole grid.
+        if let Event::Key(key_event) = event::read()? {
+            match key_event.code {
+                KeyCode::Up | KeyCode::Char('k') if cursor_pos.1 > 0 => {
+                    cursor_pos.1 -= 1;
+                }
+                KeyCode::Down | KeyCode::Char('j') if cursor_pos.1 < ULTIMATE_CELLS - 1 => {
+                    cursor_pos.1 += 1;
+                }
+                KeyCode::Left | KeyCode::Char('h') if cursor_pos.0 > 0 => {
+                    cursor_pos.0 -= 1;
+                }
+                KeyCode::Right | KeyCode::Char('l') if cursor_pos.0 < ULTIMATE_CELLS - 1 => {
+                    cursor_pos.0 += 1;
+                }
+                KeyCode::Enter | KeyCode::Char('w') => {
+                    // Make a move for the current player at the cursor's (x, y) position.
+                    let mv: Move = Move::new(state.current_player, cursor_pos.0, cursor_pos.1);
+                    let result = state.apply_move(mv);
+                    // Report the outcome of the move and end the game if it's over.
+                    if report_move(&mut stdout, &state, cursor_pos, result, true)? {
+                        break;
+                    }
+                }
+                // Take back moves or quit with the keys every game shares, and do nothing for any other key.
+                key => {
+                    if handle_shared_key(&mut state, key, &mut cursor_pos, None)? {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    // Once the loop is broken disable raw mode and return the terminal to its original state.
+    terminal::disable_raw_mode()?;
+    Ok(())
+}
+
+/// Implementation of 'TerminalGame' for UltimateState that draws the grid, and lets a person carry on after picking a cell in the wrong board, a finished board or an occupied cell.
+impl TerminalGame for UltimateState {
+    type Cursor = (usize, usize);
+    fn render(&self, cursor_pos: Self::Cursor) -> Result<(), Box<dyn std::error::Error>> {
+        render_ultimate_board(self, cursor_pos)
+    }
+    fn bottom(&self) -> u16 {
+        (ULTIMATE_CELLS * 2 + 1) as u16
+    }
+    fn current_player(&self) -> Player {
+        self.current_player
+    }
+    fn is_recoverable(&self, error: &GameError) -> bool {
+        matches!(
+            error,
+            GameError::CellOccupied | GameError::WrongBoard | GameError::BoardFinished
+        )
+    }
+    fn undo(&mut self) -> Result<Self::Cursor, Box<dyn std::error::Error>> {
+        let mv: Move = UltimateState::undo(self)?;
+        Ok((mv.x, mv.y))
+    }
+}
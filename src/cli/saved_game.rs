@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ai::Opponent;
+use crate::{BoardArgs, GameState, Generate};
+
+/// Holds what goes in the save file; the game itself, with the computer player (if any) next to it so that a resumed game keeps the same opponent.
+/// The game's fields are saved at the top level rather than nested, so save files look the same as they did when the opponent was part of the game.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SavedGame {
+    #[serde(flatten)]
+    pub state: GameState,
+    /// The computer player; games saved without one are between two people.
+    #[serde(default)]
+    pub opponent: Option<Opponent>,
+}
+
+/// Implementation for SavedGame containing functions to start a game, tell whose turn it is, and save and load it.
+impl SavedGame {
+    /// Creates a new game from the board arguments, against the computer player they ask for (if any).
+    pub fn new(boardargs: &BoardArgs) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(SavedGame {
+            state: GameState::new(boardargs)?,
+            opponent: Opponent::from_args(boardargs),
+        })
+    }
+
+    /// Returns true if the game is still going and it's the computer player's turn.
+    pub fn is_computer_turn(&self) -> bool {
+        self.opponent
+            .as_ref()
+            .is_some_and(|opponent: &Opponent| opponent.is_turn(&self.state))
+    }
+
+    /// Save game as serialized json file.
+    pub fn save_game(&self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string(&self)?;
+        std::fs::write(filename, json)?;
+        Ok(())
+    }
+    /// Load game by setting the contents of SavedGame as the deserialized contents of the json file.
+    pub fn load_game(filename: &str) -> Result<SavedGame, Box<dyn std::error::Error>> {
+        let json = std::fs::read_to_string(filename)?;
+        let mut saved: SavedGame = serde_json::from_str(&json)?;
+        saved.state.restore()?;
+        Ok(saved)
+    }
+}
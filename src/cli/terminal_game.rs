@@ -0,0 +1,124 @@
+use crossterm::{
+    cursor,
+    event::KeyCode,
+    style::{Color, Print, SetBackgroundColor},
+    ExecutableCommand,
+};
+use std::io::{self};
+
+use crate::logic::GameError;
+use crate::{GameStatus, Player};
+
+/// Trait for games played in the terminal, so that every runner reports moves and handles the keys they share in the same way.
+pub trait TerminalGame {
+    /// Where the cursor is on the board; (x, y), along with the layer or board z it's on for games that have more than one.
+    type Cursor: Copy;
+    /// Draws the board with the cursor on it.
+    fn render(&self, cursor_pos: Self::Cursor) -> Result<(), Box<dyn std::error::Error>>;
+    /// The line below the board, where the outcome of a move is reported.
+    fn bottom(&self) -> u16;
+    /// The player whose turn it is.
+    fn current_player(&self) -> Player;
+    /// Returns true for the mistakes a person can make (like picking an occupied cell) that they're let carry on after.
+    fn is_recoverable(&self, error: &GameError) -> bool;
+    /// Takes back the last move, returning the cursor position of the cell it was played in.
+    fn undo(&mut self) -> Result<Self::Cursor, Box<dyn std::error::Error>>;
+    /// The message shown when the given player wins.
+    fn describe_win(&self, winner: Player) -> String {
+        format!("Player {} wins!", winner)
+    }
+    /// Saves the game after every change, for games that can be resumed.
+    fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
+
+/// Function that reports the outcome of a move (or redo) below the board and saves the game.
+/// Returns true if the game has ended and the game loop should be broken out of.
+/// by_human is false for the computer player's moves, which would only make the same mistake again if it were let carry on.
+pub fn report_move<G: TerminalGame>(
+    stdout: &mut io::Stdout,
+    game: &G,
+    cursor_pos: G::Cursor,
+    result: Result<GameStatus, Box<dyn std::error::Error>>,
+    by_human: bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    // Matches the result of the move to find the GameStatus and act accordingly
+    match result {
+        // If the status returned is Won or Draw, then show the final board, say how the game ended and save it (saving the GameStatus).
+        Ok(status @ (GameStatus::Won(_) | GameStatus::Draw)) => {
+            game.render(cursor_pos)?;
+
+            // Move to the bottom of the board.
+            stdout.execute(cursor::MoveTo(1, game.bottom()))?;
+            match status {
+                GameStatus::Won(winner) => {
+                    stdout.execute(SetBackgroundColor(Color::Green))?;
+                    stdout.execute(Print(game.describe_win(winner)))?;
+                }
+                _ => {
+                    stdout.execute(SetBackgroundColor(Color::DarkGrey))?;
+                    stdout.execute(Print("It's a draw!"))?;
+                }
+            }
+            stdout.execute(SetBackgroundColor(Color::Reset))?;
+            stdout.execute(cursor::MoveToNextLine(0))?;
+
+            game.save()?;
+            Ok(true)
+        }
+        // If the status returned is Ongoing, then save the new mark and next player and continue the game.
+        Ok(GameStatus::Ongoing) => {
+            game.save()?;
+            Ok(false)
+        }
+        // If a person made a mistake the game lets them carry on after, do nothing.
+        Err(e)
+            if by_human
+                && e.downcast_ref::<GameError>()
+                    .is_some_and(|error: &GameError| game.is_recoverable(error)) =>
+        {
+            Ok(false)
+        }
+        // If any other error is returned, move to the bottom of the board and report the error.
+        Err(e) => {
+            stdout.execute(cursor::MoveTo(1, game.bottom()))?;
+            stdout.execute(SetBackgroundColor(Color::Red))?;
+            stdout.execute(Print(format!("Error: {}", e)))?;
+            stdout.execute(SetBackgroundColor(Color::Reset))?;
+            stdout.execute(cursor::MoveToNextLine(0))?;
+            Ok(true)
+        }
+    }
+}
+
+/// Function that handles the keys every game in the terminal shares; u takes back the last move and q or Esc quits.
+/// The computer plays the given side (if any), and its reply is taken back too so the player gets their turn back.
+/// Returns true if the game should be quit.
+pub fn handle_shared_key<G: TerminalGame>(
+    game: &mut G,
+    key: KeyCode,
+    cursor_pos: &mut G::Cursor,
+    computer: Option<Player>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    match key {
+        KeyCode::Char('u') => {
+            // Take back the last move, moving the cursor to the cell it was played in, and save the game.
+            if let Ok(undone) = game.undo() {
+                *cursor_pos = undone;
+                // If that hands the turn back to the computer, take back its move too.
+                if computer == Some(game.current_player()) {
+                    if let Ok(undone) = game.undo() {
+                        *cursor_pos = undone;
+                    }
+                }
+                game.save()?;
+            }
+            Ok(false)
+        }
+        // If esc is hit break out of the loop and exit the game.
+        KeyCode::Esc | KeyCode::Char('q') => Ok(true),
+        // If any other key is hit do nothing.
+        _ => Ok(false),
+    }
+}
@@ -1,5 +1,9 @@
+/// Import ai as a public module.
+pub mod ai;
 /// Import app as a public module.
 pub mod app;
+/// Import bot as a public module.
+pub mod bot;
 /// Import clap as a public module.
 pub mod clap;
 /// Import cli as a public module.
@@ -8,14 +12,28 @@ pub mod cli;
 pub mod completions;
 /// Import logic as a public module.
 pub mod logic;
+/// Import tournament as a public module.
+pub mod tournament;
 
 /// Publically use functions and enums from app.
 pub use app::*;
+/// Publically use the run_engine function from the engine module from the bot module.
+pub use bot::run_engine;
 /// Publically use functions and enums from clap.
 pub use clap::*;
 /// Publically use the run_game function from the run_game module from the cli module.
 pub use cli::run_game::run_game;
+/// Publically use the run_notakto function from the run_notakto module from the cli module.
+pub use cli::run_notakto::run_notakto;
+/// Publically use the run_quantum function from the run_quantum module from the cli module.
+pub use cli::run_quantum::run_quantum;
+/// Publically use the run_solve function from the run_solve module from the cli module.
+pub use cli::run_solve::run_solve;
+/// Publically use the run_ultimate function from the run_ultimate module from the cli module.
+pub use cli::run_ultimate::run_ultimate;
 /// Publically use the completions function from the generate module.
 pub use completions::completions;
-/// Publically use the GameState struct, the Generate trait for GameState, and the GameStatus, and Player enums from the logic module.
-pub use logic::{GameState, GameStatus, Generate, Player};
+/// Publically use the GameState and Geometry structs, the Generate trait for GameState, and the GameStatus, and Player enums from the logic module.
+pub use logic::{GameState, GameStatus, Generate, Geometry, Player};
+/// Publically use the run_tournament function from the tournament module.
+pub use tournament::run_tournament;
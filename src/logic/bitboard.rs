@@ -0,0 +1,121 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use super::{generate::winning_combinations, Geometry};
+
+/// Set of board cells stored one bit per cell, so that whole lines can be checked with a couple of bitwise operations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Bitboard {
+    /// Boards of up to 128 cells fit in a single integer (a 3x3 board only uses the low 9 bits).
+    Small(u128),
+    /// Bigger boards use as many 64 bit words as they need.
+    Large(Vec<u64>),
+}
+
+/// Implementation for Bitboard containing functions to set and test cells.
+impl Bitboard {
+    /// Creates an empty bitboard for a board of len cells.
+    pub fn empty(len: usize) -> Self {
+        if len <= 128 {
+            Bitboard::Small(0)
+        } else {
+            Bitboard::Large(vec![0; len.div_ceil(64)])
+        }
+    }
+
+    /// Creates a bitboard for a board of len cells with the given cells set.
+    pub fn from_indexes(len: usize, indexes: &[usize]) -> Self {
+        let mut bitboard: Bitboard = Bitboard::empty(len);
+        for &index in indexes {
+            bitboard.set(index);
+        }
+        bitboard
+    }
+
+    /// Adds the cell at index.
+    pub fn set(&mut self, index: usize) {
+        match self {
+            Bitboard::Small(bits) => *bits |= 1 << index,
+            Bitboard::Large(words) => words[index / 64] |= 1 << (index % 64),
+        }
+    }
+
+    /// Removes the cell at index.
+    pub fn clear(&mut self, index: usize) {
+        match self {
+            Bitboard::Small(bits) => *bits &= !(1 << index),
+            Bitboard::Large(words) => words[index / 64] &= !(1 << (index % 64)),
+        }
+    }
+
+    /// Returns true if the cell at index is set.
+    pub fn get(&self, index: usize) -> bool {
+        match self {
+            Bitboard::Small(bits) => bits >> index & 1 == 1,
+            Bitboard::Large(words) => words[index / 64] >> (index % 64) & 1 == 1,
+        }
+    }
+
+    /// Returns true if every cell set in mask is also set here (both bitboards must be for the same board).
+    pub fn contains(&self, mask: &Bitboard) -> bool {
+        match (self, mask) {
+            (Bitboard::Small(bits), Bitboard::Small(mask)) => bits & mask == *mask,
+            (Bitboard::Large(words), Bitboard::Large(mask)) => words
+                .iter()
+                .zip(mask)
+                .all(|(word, mask): (&u64, &u64)| word & mask == *mask),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of cells set.
+    pub fn count(&self) -> usize {
+        match self {
+            Bitboard::Small(bits) => bits.count_ones() as usize,
+            Bitboard::Large(words) => words
+                .iter()
+                .map(|word: &u64| word.count_ones() as usize)
+                .sum(),
+        }
+    }
+}
+
+/// Implementation of Default for Bitboard that gives an empty bitboard for a board without any cells.
+impl Default for Bitboard {
+    fn default() -> Self {
+        Bitboard::empty(0)
+    }
+}
+
+/// Holds a line a player can win with; the bitboard of its cells along with their board indexes, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineMask {
+    pub mask: Bitboard,
+    pub cells: Vec<usize>,
+}
+
+/// Line masks already built, keyed by the geometry and win length they were built for.
+type LineMaskCache = HashMap<(Geometry, usize), Rc<[LineMask]>>;
+
+thread_local! {
+    static LINE_MASKS: RefCell<LineMaskCache> = RefCell::new(HashMap::new());
+}
+
+/// Function that returns the mask of every winning line of length win_length on a board with the given geometry, in the same order as winning_combinations().
+/// The masks for each board are only built the first time they're asked for.
+pub fn line_masks(geometry: Geometry, win_length: usize) -> Rc<[LineMask]> {
+    LINE_MASKS.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry((geometry, win_length))
+            .or_insert_with(|| {
+                winning_combinations(geometry, win_length)
+                    .into_iter()
+                    .map(|cells: Vec<usize>| LineMask {
+                        mask: Bitboard::from_indexes(geometry.len(), &cells),
+                        cells,
+                    })
+                    .collect()
+            })
+            .clone()
+    })
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GameError, Symbol};
+
+/// Enum to represent a single cell on the board: either empty, marked with a symbol, or holding a number (in numerical tic-tac-toe).
+/// Cells are saved as the same chars that get displayed (' ', 'X', 'O' or a digit from 1 to 9) so save files stay readable.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(try_from = "char", into = "char")]
+pub enum Cell {
+    Empty,
+    Occupied(Symbol),
+    Number(u8),
+}
+
+/// Implementation to convert a char from a save file into a cell, returning an error for any char that isn't ' ', 'X', 'O' or a digit from 1 to 9.
+impl TryFrom<char> for Cell {
+    type Error = GameError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            ' ' => Ok(Cell::Empty),
+            'X' => Ok(Cell::Occupied(Symbol::X)),
+            'O' => Ok(Cell::Occupied(Symbol::O)),
+            '1'..='9' => Ok(Cell::Number(value as u8 - b'0')),
+            _ => Err(GameError::InvalidCell(value)),
+        }
+    }
+}
+
+/// Implementation to convert a cell into the char it is saved as.
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Empty => ' ',
+            Cell::Occupied(Symbol::X) => 'X',
+            Cell::Occupied(Symbol::O) => 'O',
+            Cell::Number(number) => char::from(b'0' + number),
+        }
+    }
+}
+
+/// Implementation to display cell as a string (" ", "X", "O" or its number).
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
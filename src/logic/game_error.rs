@@ -2,8 +2,24 @@
 #[derive(Debug)]
 pub enum GameError {
     InvalidBoardSize,
+    InvalidWinLength,
     EmptyBoard,
     GameOver,
+    CellOccupied,
+    OutOfBounds,
+    NotYourTurn,
+    InvalidCell(char),
+    NothingToUndo,
+    NothingToRedo,
+    WrongBoard,
+    BoardFinished,
+    WrongSymbol,
+    SameCell,
+    CollapsePending,
+    NothingToCollapse,
+    InvalidCollapse,
+    InvalidNumber,
+    InvalidHistory,
 }
 
 /// Implementation of std::fmt::Display for GameError to display message based on error.
@@ -11,8 +27,34 @@ impl std::fmt::Display for GameError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             GameError::InvalidBoardSize => write!(f, "The board size is invalid."),
+            GameError::InvalidWinLength => write!(f, "The win length is invalid."),
             GameError::EmptyBoard => write!(f, "The board is empty."),
-            GameError::GameOver => write!(f, "Cannot load a game that is over."),
+            GameError::GameOver => write!(f, "The game is already over."),
+            GameError::CellOccupied => write!(f, "That cell is already occupied."),
+            GameError::OutOfBounds => write!(f, "That cell is not on the board."),
+            GameError::NotYourTurn => write!(f, "It is not that player's turn."),
+            GameError::InvalidCell(cell) => {
+                write!(f, "The board contains an invalid cell: {:?}.", cell)
+            }
+            GameError::NothingToUndo => write!(f, "There are no moves to undo."),
+            GameError::NothingToRedo => write!(f, "There are no moves to redo."),
+            GameError::WrongBoard => {
+                write!(f, "That move has to be played in the highlighted board.")
+            }
+            GameError::BoardFinished => write!(f, "That board has already been won or drawn."),
+            GameError::WrongSymbol => write!(f, "That player can only mark with their own symbol."),
+            GameError::SameCell => write!(f, "A spooky mark has to go in two different cells."),
+            GameError::CollapsePending => write!(f, "The cycle has to be collapsed first."),
+            GameError::NothingToCollapse => write!(f, "There is no cycle to collapse."),
+            GameError::InvalidCollapse => {
+                write!(f, "The mark can only collapse into one of its two cells.")
+            }
+            GameError::InvalidNumber => {
+                write!(f, "That number isn't one the player has left to play.")
+            }
+            GameError::InvalidHistory => {
+                write!(f, "The move history doesn't match the board.")
+            }
         }
     }
 }
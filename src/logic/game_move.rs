@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Cell, Player, Symbol};
+
+/// Holds a single move; the player making it and the (x, y) position of the cell they want to mark, along with the layer z it's on for 3D boards and the symbol (or number) they mark it with.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub player: Player,
+    pub x: usize,
+    pub y: usize,
+    /// The layer of the cell; moves saved before 3D boards existed are on the first (and only) layer.
+    #[serde(default)]
+    pub z: usize,
+    /// The symbol chosen for the cell, or None to mark it with the player's own symbol (the only choice unless the rules let players pick).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<Symbol>,
+    /// The number played in the cell in numerical tic-tac-toe, or None in every other variant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<u8>,
+}
+
+/// Implementation for Move containing constructors and the symbol or number the move marks with.
+impl Move {
+    /// Creates a new move for the player at (x, y) on the first layer, marking it with their own symbol.
+    pub fn new(player: Player, x: usize, y: usize) -> Self {
+        Move {
+            player,
+            x,
+            y,
+            z: 0,
+            symbol: None,
+            number: None,
+        }
+    }
+    /// Returns the same move on layer z.
+    pub fn on_layer(self, z: usize) -> Self {
+        Move { z, ..self }
+    }
+    /// Returns the same move marking the cell with symbol.
+    pub fn with_symbol(self, symbol: Symbol) -> Self {
+        Move {
+            symbol: Some(symbol),
+            ..self
+        }
+    }
+    /// Returns the same move playing number in the cell.
+    pub fn with_number(self, number: u8) -> Self {
+        Move {
+            number: Some(number),
+            ..self
+        }
+    }
+    /// Returns the symbol the move marks its cell with.
+    pub fn symbol(&self) -> Symbol {
+        self.symbol.unwrap_or(Symbol::from(self.player))
+    }
+    /// Returns what the move puts in its cell; its number if it has one, otherwise its symbol.
+    pub fn mark(&self) -> Cell {
+        match self.number {
+            Some(number) => Cell::Number(number),
+            None => Cell::Occupied(self.symbol()),
+        }
+    }
+}
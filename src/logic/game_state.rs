@@ -1,17 +1,314 @@
 use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 
-use super::{game_error::GameError, player::Player, GameStatus};
+use super::{
+    bitboard::{line_masks, LineMask},
+    game_error::GameError,
+    generate::{directions, Line},
+    player::Player,
+    Bitboard, Cell, GameStatus, Geometry, Move, Rules, Symbol, Variant,
+};
 
-/// Holds the state of the game; including the board, current player, and game status.
+/// Holds the state of the game; including the board and its dimensions, current player, game status, the number of marks in a row needed to win, the move history, the lines that won the game, and the rule variant.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct GameState {
-    pub board: Vec<char>,
+    pub board: Vec<Cell>,
+    /// The dimensions of the board; games saved before boards could be rectangular are loaded as a square board (see load_game()).
+    #[serde(default = "Geometry::empty")]
+    pub geometry: Geometry,
     pub current_player: Player,
     pub status: GameStatus,
+    /// The number of marks in a row needed to win; games saved before it could be set are loaded with the board's shorter side (see load_game()).
+    #[serde(default)]
+    pub win_length: usize,
+    /// Every move played so far, in order.
+    #[serde(default)]
+    pub history: Vec<Move>,
+    /// Moves that have been undone and can be redone, with the next move to redo last.
+    #[serde(default)]
+    pub undone: Vec<Move>,
+    /// The (x, y) coordinates of every line that won the game, empty while nobody has won.
+    #[serde(default)]
+    pub winning_lines: Vec<Line>,
+    /// The rules the game is played with; games saved before variants existed are standard games.
+    #[serde(default)]
+    pub variant: Variant,
+    /// The number of marked cells, so that a full board can be spotted without scanning it.
+    /// It isn't saved; apply_move() and undo() keep it up to date, and anything that sets the board directly has to call recount().
+    #[serde(skip)]
+    pub occupied: usize,
+    /// The cells marked with each symbol (X's then O's), so that lines of a symbol can be checked a whole line at a time.
+    /// Like the occupied count they aren't saved, and are kept up to date by apply_move() and undo() or rebuilt by recount().
+    #[serde(skip)]
+    pub bitboards: [Bitboard; 2],
 }
 
-/// Implementation for GameState containing functions to serialize the current game and load game from deserialized save file.
+/// Implementation for GameState containing functions to apply, undo and redo moves, serialize the current game and load game from deserialized save file.
 impl GameState {
+    /// Apply a move to the board, updating the game status and passing the turn to the other player if the game is still ongoing.
+    /// The move is rejected (and the state left untouched) if the game is over, it's not the mover's turn, it marks with a symbol or number the mover can't use, or the cell is off the board or already occupied.
+    /// Playing a new move throws away any moves that could have been redone.
+    pub fn apply_move(&mut self, mv: Move) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        let status: GameStatus = self.play(mv)?;
+        self.undone.clear();
+        Ok(status)
+    }
+
+    /// Take back the last move, restoring the game status and handing the turn back to the player who made it.
+    /// The move is rejected (and the state left untouched) if it isn't the mark in its cell, which can only happen if the board was set directly.
+    pub fn undo(&mut self) -> Result<Move, Box<dyn std::error::Error>> {
+        let mv: Move = *self.history.last().ok_or(GameError::NothingToUndo)?;
+        if !self.is_on_board(mv) {
+            return Err(Box::new(GameError::InvalidHistory));
+        }
+        let occupied: usize = self
+            .occupied
+            .checked_sub(1)
+            .ok_or(GameError::InvalidHistory)?;
+        self.history.pop();
+        let index: usize = self.geometry.index_3d(mv.x, mv.y, mv.z);
+        if let Cell::Occupied(symbol) = self.board[index] {
+            self.bitboards[symbol as usize].clear(index);
+        }
+        self.board[index] = Cell::Empty;
+        self.occupied = occupied;
+        // Any move in the history was played while the game was ongoing, so taking one back always leaves an ongoing game.
+        self.status = GameStatus::Ongoing;
+        self.winning_lines.clear();
+        self.current_player = mv.player;
+        self.undone.push(mv);
+        Ok(mv)
+    }
+
+    /// Replay the last move that was undone.
+    pub fn redo(&mut self) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        let mv: Move = *self.undone.last().ok_or(GameError::NothingToRedo)?;
+        let status: GameStatus = self.play(mv)?;
+        self.undone.pop();
+        Ok(status)
+    }
+
+    /// Validate and play a move, recording it in the history.
+    fn play(&mut self, mv: Move) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        // A finished game can't be played on.
+        if self.status != GameStatus::Ongoing {
+            return Err(Box::new(GameError::GameOver));
+        }
+        // Only the current player can move.
+        if mv.player != self.current_player {
+            return Err(Box::new(GameError::NotYourTurn));
+        }
+        // The symbol has to be the mover's own unless the rules let them pick,
+        if !self.variant.chooses_symbols() && mv.symbol() != Symbol::from(mv.player) {
+            return Err(Box::new(GameError::WrongSymbol));
+        }
+        // a number has to be one the mover hasn't played yet (which rules out any number if the rules don't have them), and there has to be one if they do,
+        let valid_number: bool = match mv.number {
+            Some(number) => self.available_numbers(mv.player).contains(&number),
+            None => !self.variant.uses_numbers(),
+        };
+        if !valid_number {
+            return Err(Box::new(GameError::InvalidNumber));
+        }
+        // the cell has to be on the board,
+        if !self.geometry.contains_3d(mv.x, mv.y, mv.z) {
+            return Err(Box::new(GameError::OutOfBounds));
+        }
+        // The board itself has to match its dimensions (a save file could have been edited),
+        if self.board.len() != self.geometry.len() {
+            return Err(Box::new(GameError::InvalidBoardSize));
+        }
+        // and the cell the mark lands in (which is the one it was played in unless marks fall) has to be empty.
+        let (x, y) = self.landing_cell(mv.x, mv.y, mv.z);
+        let mv: Move = Move { x, y, ..mv };
+        let index: usize = self.geometry.index_3d(mv.x, mv.y, mv.z);
+        if self.board[index] != Cell::Empty {
+            return Err(Box::new(GameError::CellOccupied));
+        }
+        // Mark the cell and check only the lines through it, since the game was still going before it.
+        self.board[index] = mv.mark();
+        if let Cell::Occupied(symbol) = self.board[index] {
+            self.bitboards[symbol as usize].set(index);
+        }
+        self.occupied += 1;
+        self.history.push(mv);
+        (self.status, self.winning_lines) = self.check_last_move(index, mv.player);
+        // Only pass the turn on if there is still a game to play.
+        if self.status == GameStatus::Ongoing {
+            self.current_player = mv.player.other();
+        }
+        Ok(self.status.clone())
+    }
+
+    /// Checks for a completed line through the mark at index (placed by mover), returning the status along with the (x, y) coordinates of every completed line.
+    /// Only the lines through that mark are checked, and a full board is spotted from the occupied count, so this gives the same result as check_winner() as long as nobody had won before the mark was placed.
+    pub fn check_last_move(&self, index: usize, mover: Player) -> (GameStatus, Vec<Line>) {
+        let lines: Vec<Line> = match self.board[index] {
+            Cell::Empty => return (GameStatus::Ongoing, Vec::new()),
+            Cell::Occupied(symbol) => self.runs_through(index, symbol),
+            Cell::Number(_) => self.lines_through(index),
+        };
+        if !lines.is_empty() {
+            let owner: Player = self.variant.line_owner(self.board[index], mover);
+            return (self.variant.completed_line(owner), lines);
+        }
+        // Without a winner, a full board is a draw (or whatever else the rules make it).
+        if self.occupied == self.board.len() {
+            return (self.variant.board_filled(), Vec::new());
+        }
+        (GameStatus::Ongoing, Vec::new())
+    }
+
+    /// Returns every completed line of symbol through the mark at index, in the order check_winner() finds them in.
+    /// Lines of a symbol are runs of it, so only the directions through the mark are walked (four on a flat board, thirteen on a 3D one) rather than every line.
+    fn runs_through(&self, index: usize, symbol: Symbol) -> Vec<Line> {
+        let point: Vec<usize> = self.geometry.point(index);
+        let win_length: isize = self.win_length as isize;
+        // Whether runs longer than a line don't count (a single mark has no run to be part of, so it always counts).
+        let exact: bool = self.variant.exact_lines() && win_length > 1;
+        // Holds each winning line along with the index of its first cell and its direction, to sort them into the order check_winner() finds them in.
+        let mut lines: Vec<(usize, usize, Line)> = Vec::new();
+        for (direction, steps) in directions(point.len()).iter().enumerate() {
+            // Count how far the same symbol runs back and forward from the mark (no more than a line's length is needed, or one past a line's length for exact lines).
+            let run = |sign: isize| -> isize {
+                (1..=win_length)
+                    .take_while(|&distance: &isize| {
+                        self.geometry
+                            .step(&point, steps, distance * sign)
+                            .is_some_and(|cell: usize| self.board[cell] == Cell::Occupied(symbol))
+                    })
+                    .count() as isize
+            };
+            let (back, forward) = (run(-1), run(1));
+            let starts: RangeInclusive<isize> = if !exact {
+                // Every stretch of win_length cells of that run that includes the mark is a winning line,
+                -back.min(win_length - 1)..=forward.min(win_length - 1) - (win_length - 1)
+            } else {
+                // but with exact lines only a run of exactly win_length cells is one.
+                -back..=forward - (win_length - 1)
+            };
+            for start in starts.filter(|_| !exact || back + forward + 1 == win_length) {
+                let cells: Vec<usize> = (start..start + win_length)
+                    .filter_map(|distance: isize| self.geometry.step(&point, steps, distance))
+                    .collect();
+                let line: Line = cells
+                    .iter()
+                    .map(|&cell: &usize| self.geometry.coords_3d(cell))
+                    .collect();
+                lines.push((cells[0], direction, line));
+            }
+        }
+        lines.sort_by_key(|(start, direction, _)| (*start, *direction));
+        lines.into_iter().map(|(_, _, line)| line).collect()
+    }
+
+    /// Returns every line through the cell at index that the rules count as completed, in the order check_winner() finds them in.
+    /// This is for lines that aren't runs of a symbol (like lines of numbers adding up to a target), so it checks each line through the cell in full.
+    fn lines_through(&self, index: usize) -> Vec<Line> {
+        line_masks(self.geometry, self.win_length)
+            .iter()
+            .filter(|line: &&LineMask| line.cells.contains(&index))
+            .filter(|line: &&LineMask| {
+                let cells: Vec<Cell> = line
+                    .cells
+                    .iter()
+                    .map(|&cell: &usize| self.board[cell])
+                    .collect();
+                self.variant.completes_line(&cells)
+            })
+            .map(|line: &LineMask| {
+                line.cells
+                    .iter()
+                    .map(|&cell: &usize| self.geometry.coords_3d(cell))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the cell a mark played at (x, y) on layer z ends up in; the lowest empty cell of column x on that layer if marks fall, otherwise (x, y) itself.
+    /// A full column gives its top cell, which is occupied.
+    pub fn landing_cell(&self, x: usize, y: usize, z: usize) -> (usize, usize) {
+        if !self.variant.drops_marks() {
+            return (x, y);
+        }
+        let lowest_empty: Option<usize> = (0..self.geometry.height)
+            .rev()
+            .find(|&row: &usize| self.board[self.geometry.index_3d(x, row, z)] == Cell::Empty);
+        (x, lowest_empty.unwrap_or(0))
+    }
+
+    /// Recounts the marked cells and rebuilds the bitboards, for after the board has been set directly rather than through apply_move() and undo().
+    pub fn recount(&mut self) {
+        self.occupied = self
+            .board
+            .iter()
+            .filter(|cell: &&Cell| **cell != Cell::Empty)
+            .count();
+        self.bitboards = [Symbol::X, Symbol::O].map(|symbol: Symbol| {
+            let mut bitboard: Bitboard = Bitboard::empty(self.board.len());
+            for (index, cell) in self.board.iter().enumerate() {
+                if *cell == Cell::Occupied(symbol) {
+                    bitboard.set(index);
+                }
+            }
+            bitboard
+        });
+    }
+
+    /// Returns every move the current player could make, or nothing if the game is over.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        if self.status != GameStatus::Ongoing {
+            return Vec::new();
+        }
+        // Each playable cell can take any number the player has left if the rules have numbers, either symbol if the rules let the player pick, otherwise only their own symbol.
+        let marks: Vec<(Option<Symbol>, Option<u8>)> = if self.variant.uses_numbers() {
+            self.available_numbers(self.current_player)
+                .into_iter()
+                .map(|number: u8| (None, Some(number)))
+                .collect()
+        } else if self.variant.chooses_symbols() {
+            vec![(Some(Symbol::X), None), (Some(Symbol::O), None)]
+        } else {
+            vec![(None, None)]
+        };
+        // Only the cells marks can land in are playable, which are all of the empty ones unless marks fall.
+        (0..self.board.len())
+            .filter(|&index: &usize| self.board[index] == Cell::Empty)
+            .map(|index: usize| self.geometry.coords_3d(index))
+            .filter(|&(x, y, z): &(usize, usize, usize)| self.landing_cell(x, y, z) == (x, y))
+            .flat_map(|(x, y, z): (usize, usize, usize)| {
+                marks.iter().map(
+                    move |&(symbol, number): &(Option<Symbol>, Option<u8>)| Move {
+                        symbol,
+                        number,
+                        ..Move::new(self.current_player, x, y).on_layer(z)
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the numbers player can still play; the rules' numbers for them that aren't on the board yet.
+    pub fn available_numbers(&self, player: Player) -> Vec<u8> {
+        self.variant
+            .numbers(player)
+            .into_iter()
+            .filter(|&number: &u8| !self.board.contains(&Cell::Number(number)))
+            .collect()
+    }
+
+    /// Returns the bitboard of the cells marked with symbol.
+    pub fn bitboard(&self, symbol: Symbol) -> &Bitboard {
+        &self.bitboards[symbol as usize]
+    }
+
+    /// Returns true if (x, y) on layer z is part of a line that won the game.
+    pub fn is_winning_cell(&self, x: usize, y: usize, z: usize) -> bool {
+        self.winning_lines
+            .iter()
+            .any(|line: &Line| line.contains(&(x, y, z)))
+    }
+
     /// Save game as serialized json file.
     pub fn save_game(&self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
         let json = serde_json::to_string(&self)?;
@@ -21,10 +318,43 @@ impl GameState {
     /// Load game by setting the contents of GameState as the deserialized contents of the json file.
     pub fn load_game(filename: &str) -> Result<GameState, Box<dyn std::error::Error>> {
         let json = std::fs::read_to_string(filename)?;
-        let state: GameState = serde_json::from_str(&json)?;
-        match state.status {
-            GameStatus::Ongoing => Ok(state),
+        let mut state: GameState = serde_json::from_str(&json)?;
+        state.restore()?;
+        Ok(state)
+    }
+    /// Finishes loading a game; filling in what older saves left out, recounting the marks, and rejecting the game if it's already over,
+    /// or if its board or history don't fit together (a save file could have been edited).
+    pub fn restore(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        // Games saved without the board's dimensions were played on a square board.
+        if self.geometry.is_empty() {
+            let side: usize = self.board.len().isqrt();
+            self.geometry = Geometry::new(side, side);
+        }
+        // Games saved without a win length were played with lines as long as the board's shorter side.
+        if self.win_length == 0 {
+            self.win_length = self.geometry.width.min(self.geometry.height);
+        }
+        // The board has to match its dimensions, which also catches a board that isn't square among games saved without them.
+        if self.board.len() != self.geometry.len() {
+            return Err(Box::new(GameError::InvalidBoardSize));
+        }
+        self.recount();
+        // Every move in the history has to be the mark in its cell, one for each mark on the board (games saved before there was a history have none to check).
+        if !self.history.is_empty()
+            && (self.history.len() != self.occupied
+                || !self.history.iter().all(|&mv: &Move| self.is_on_board(mv)))
+        {
+            return Err(Box::new(GameError::InvalidHistory));
+        }
+        match self.status {
+            GameStatus::Ongoing => Ok(()),
             _ => Err(GameError::GameOver.into()),
         }
     }
+
+    /// Returns true if the move's mark is in its cell on the board, as it is for every move in the history of a game played through apply_move().
+    fn is_on_board(&self, mv: Move) -> bool {
+        self.geometry.contains_3d(mv.x, mv.y, mv.z)
+            && self.board.get(self.geometry.index_3d(mv.x, mv.y, mv.z)) == Some(&mv.mark())
+    }
 }
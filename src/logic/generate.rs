@@ -1,6 +1,15 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use crate::BoardArgs;
 
-use super::{GameError, GameState, GameStatus, Player};
+use super::{
+    bitboard::{line_masks, LineMask},
+    Bitboard, Cell, GameError, GameState, GameStatus, Geometry, Move, Player, Rules, Symbol,
+    Variant,
+};
+
+/// A line of (x, y, z) coordinates on the board (z is always 0 on a flat board).
+pub type Line = Vec<(usize, usize, usize)>;
 
 /// Trait for generating and checking the game board.
 pub trait Generate {
@@ -8,102 +17,234 @@ pub trait Generate {
     fn new(boardargs: &BoardArgs) -> Result<Self, Box<dyn std::error::Error>>
     where
         Self: Sized; // Make sure size of Self is set at compile time.
-    /// Checks if there is a winner on the board.
+    /// Checks if there is a winner on the board, returning the status along with the (x, y) coordinates of every winning line.
     fn check_winner(
-        boardargs: &BoardArgs,
-        state: GameState,
-    ) -> Result<GameStatus, Box<dyn std::error::Error>>;
+        state: &GameState,
+    ) -> Result<(GameStatus, Vec<Line>), Box<dyn std::error::Error>>;
 }
 
 /// Implementation of 'Generate' for GameState that makes a new GameState, and checks winner based on GameState.
 impl Generate for GameState {
     fn new(boardargs: &BoardArgs) -> Result<Self, Box<dyn std::error::Error>> {
-        // Set size of board and handle errors.
-        let size: usize = match boardargs.size {
-            Some(size) => size,
-            None => Err(GameError::InvalidBoardSize)?,
+        // Set the number of layers of the board, which is flat unless a depth is given.
+        let depth: usize = boardargs.shape.depth.unwrap_or(1);
+        // Set size of board; without one, use the variant's own board (and win length) if it has one and the board is flat, or a cube as deep as the board for a 3D board, or a 3x3 board.
+        let (default_geometry, default_win_length) = match (
+            boardargs.shape.size,
+            boardargs.shape.variant.default_board(),
+        ) {
+            (None, Some((geometry, win_length))) if depth == 1 => (geometry, Some(win_length)),
+            (size, _) => {
+                let size: usize = size.unwrap_or(if depth > 1 { depth } else { 3 });
+                (Geometry::new(size, size), None)
+            }
         };
-        // Initialize the board as a vector of spaces, with the number of elements equal to size^2.
-        let board: Vec<char> = vec![' '; size * size];
+        // Set the width and height of the board, defaulting both to the size of the board.
+        let geometry: Geometry = Geometry::new(
+            boardargs.shape.width.unwrap_or(default_geometry.width),
+            boardargs.shape.height.unwrap_or(default_geometry.height),
+        )
+        .with_depth(depth);
+        // A board without any rows or columns can't be played on.
+        if geometry.is_empty() {
+            Err(GameError::InvalidBoardSize)?
+        }
+        // Set the number of marks in a row needed to win, defaulting to the variant's win length or the shortest side of the board.
+        let dimensions: Vec<usize> = geometry.dimensions();
+        let win_length: usize = boardargs
+            .shape
+            .win_length
+            .or(default_win_length)
+            .or(dimensions.iter().copied().min())
+            .unwrap_or(0);
+        // A line can't be longer than the longest side of the board or shorter than a single mark.
+        if win_length == 0 || dimensions.iter().all(|&side: &usize| win_length > side) {
+            Err(GameError::InvalidWinLength)?
+        }
+        // Numerical tic-tac-toe only works on a flat 3x3 board with lines of three, since its numbers add up to the target in exactly the lines of a magic square.
+        if boardargs.shape.variant == Variant::Numerical {
+            if geometry != Geometry::new(3, 3) {
+                Err(GameError::InvalidBoardSize)?
+            }
+            if win_length != 3 {
+                Err(GameError::InvalidWinLength)?
+            }
+        }
+        // Initialize the board as a vector of empty cells, with the number of elements equal to width * height * depth.
+        let board: Vec<Cell> = vec![Cell::Empty; geometry.len()];
         // Return board as an Ok value.
         Ok(GameState {
             board,
+            geometry,
             current_player: Player::X,   // Default starting player.
             status: GameStatus::Ongoing, // Default game status.
+            win_length,
+            history: Vec::new(),
+            undone: Vec::new(),
+            winning_lines: Vec::new(),
+            occupied: 0,
+            bitboards: [
+                Bitboard::empty(geometry.len()),
+                Bitboard::empty(geometry.len()),
+            ],
+            variant: boardargs.shape.variant,
         })
     }
-    fn check_winner(
-        boardargs: &BoardArgs,
-        state: Self,
-    ) -> Result<GameStatus, Box<dyn std::error::Error>> {
-        // Get size of board.
-        let size: usize = boardargs.size.unwrap();
+    fn check_winner(state: &Self) -> Result<(GameStatus, Vec<Line>), Box<dyn std::error::Error>> {
+        // Get the dimensions of the board.
+        let geometry: Geometry = state.geometry;
         // Check if the board is valid size.
-        if state.board.len() != size * size {
+        if state.board.len() != geometry.len() {
             return Err(Box::new(GameError::InvalidBoardSize));
         }
         // Check if the board is empty.
         if state.board.is_empty() {
             return Err(Box::new(GameError::EmptyBoard));
         }
-        // If there are no errors get board.
-        let board = &state.board;
-        // Get winning combinations.
-        let winning_combinations: Vec<Vec<usize>> = (0..size) // Sets the range as from 0 to size - 1
-            .flat_map(|index: usize| {
-                // For each index (row and column), create a row and column vector:
+        // Get the (cached) mask of every winning line.
+        let line_masks: Rc<[LineMask]> = line_masks(geometry, state.win_length);
 
-                // Creates a vector containing the values of a row.
-                // For example if the index = 0, and size = 3 the row's values would be: 0..3 = [0, 1 ,2] and repeats for each value in index.
-                let row: Vec<usize> = (index * size..(index + 1) * size).collect::<Vec<usize>>();
+        // Whether lines that are part of a longer run don't count (a single mark has no run to be part of, so it always counts).
+        let exact: bool = state.variant.exact_lines() && state.win_length > 1;
 
-                // Creates a vector containing the values of a column.
-                // For example if the index = 0, and size = 3 the column's values would be: 0..9 = [0, 1, 2, 3, 4, 5, 6, 7, 8].
-                // This would obviously be problematic that's why the step_by() takes the initial index and every size-th element meaning it returns [0, 3, 6] and repeats for each value in index.
-                let col: Vec<usize> = (index..size * size).step_by(size).collect::<Vec<usize>>();
+        // The last mover, whose completed line it is in rules where that matters (or the player to move's opponent in a position without a history).
+        let mover: Player = state
+            .history
+            .last()
+            .map_or(state.current_player.other(), |mv: &Move| mv.player);
 
-                // Combines the row and column vectors into a single vector and returns them.
-                vec![row, col]
-            })
-            // The chain method concatenates the results of the rows and columns with the horizontals withing the chain method.
-            .chain(vec![
-                // Creates diagonal from top-left to bottom-right.
-                // The step_by(size + 1) returns the next diagonal value from the flattened range.
-                // For example if the index = 0, and size = 3 the diagonal's value would be: 0..9, and the step_by() method simply returns the initial index and every size + 1-th element.
-                // Which would then in-turn return: [2, 4, 6].
-                (0..size * size).step_by(size + 1).collect(),
-                // Creates diagonal from top-right to bottom-left.
-                // The step_by(size - 1) returns the next diagonal value from the flattened range.
-                // For example if the index = 0, and size = 3 the diagonal's value would be: 2..8, and the step_by() method simply returns the initial index and every size - 1-th element.
-                // Which would then in-turn return: [2, 4, 6].
-                ((size - 1)..(size * size - 1)).step_by(size - 1).collect(),
-            ])
-            // Collects the chained iterater into a vector of vectors containing values of usize which holds the winning combinations.
-            .collect::<Vec<Vec<usize>>>();
+        // Holds the player the completed lines (if any) belong to and the lines themselves.
+        let mut winner: Option<Player> = None;
+        let mut winning_lines: Vec<Line> = Vec::new();
 
-        // Iterates through each combination in winning_combinations (combo is a singular winning combination).
-        for combo in winning_combinations {
-            // combo[0] gets the index of the first element in the winning combination.
-            // board[combo[0]] gets the character at the index of the first element in the winning combination.
-            let first: char = board[combo[0]];
-            if first != ' ' // If the first char at the index of the first element in the winning combination is not empty
-                && combo 
+        // Iterates through each winning line, checking if it's been completed (and, if a line has been found already, that it belongs to the same player).
+        for line in line_masks.iter() {
+            // Lines of numbers are left to the rules to add up, while a line of a symbol is complete once that symbol's bitboard holds every cell of it.
+            let first: Cell = if state.variant.uses_numbers() {
+                let cells: Vec<Cell> = line
+                    .cells
                     .iter()
-                    .all(|&board_index: &usize| board[board_index] == first) // and if each char in the iterated index in the winning combination has the same value as the first 
-            {
-                let winner = match first {
-                    'X' => Player::X,
-                    'O' => Player::O,
-                    _ => unreachable!(),
-                };
-                return Ok(GameStatus::Won(winner)); // return the value of the first index as the winner.
+                    .map(|&board_index: &usize| state.board[board_index])
+                    .collect();
+                if !state.variant.completes_line(&cells) {
+                    continue;
+                }
+                cells[0]
+            } else {
+                match [Symbol::X, Symbol::O]
+                    .into_iter()
+                    .find(|&symbol: &Symbol| state.bitboard(symbol).contains(&line.mask))
+                {
+                    Some(symbol) if !(exact && is_overlong(state, &line.cells)) => {
+                        Cell::Occupied(symbol)
+                    }
+                    _ => continue,
+                }
+            };
+            // The rules decide whose line it is.
+            let owner: Player = state.variant.line_owner(first, mover);
+            if winner.is_none_or(|winner: Player| winner == owner) {
+                // If it counts, the line is converted into (x, y, z) coordinates and kept.
+                winner = Some(owner);
+                winning_lines.push(
+                    line.cells
+                        .iter()
+                        .map(|&board_index: &usize| geometry.coords_3d(board_index))
+                        .collect(),
+                );
             }
         }
-        // If the board does not contain any white spaces and does not satisfy any of the above return the Ok value as a draw.
-        if !board.contains(&' ') {
-            return Ok(GameStatus::Draw);
+        // If a line was completed let the rules decide who that wins the game for, and return every line that was completed.
+        if let Some(owner) = winner {
+            return Ok((state.variant.completed_line(owner), winning_lines));
+        }
+        // If every cell has been marked and does not satisfy any of the above return the Ok value as a draw (or whatever else the rules make a full board).
+        if state.board.iter().all(|cell: &Cell| *cell != Cell::Empty) {
+            return Ok((state.variant.board_filled(), winning_lines));
         }
         // If the current game board does not satisfy any of the above then the game is currently ongoing.
-        Ok(GameStatus::Ongoing)
+        Ok((GameStatus::Ongoing, winning_lines))
+    }
+}
+
+/// Function that returns true if the symbol marking a line (of at least two cells) carries on past either end of it, making it part of a longer run.
+fn is_overlong(state: &GameState, cells: &[usize]) -> bool {
+    let geometry: Geometry = state.geometry;
+    let first: Vec<usize> = geometry.point(cells[0]);
+    let last: Vec<usize> = geometry.point(cells[cells.len() - 1]);
+    // The line's direction is the step from its first cell to its second.
+    let direction: Vec<isize> = first
+        .iter()
+        .zip(geometry.point(cells[1]))
+        .map(|(&from, to): (&usize, usize)| to as isize - from as isize)
+        .collect();
+    [
+        geometry.step(&first, &direction, -1),
+        geometry.step(&last, &direction, 1),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|cell: usize| state.board[cell] == state.board[cells[0]])
+}
+
+/// Directions already built, keyed by the number of dimensions they were built for.
+type DirectionCache = HashMap<usize, Rc<[Vec<isize>]>>;
+
+thread_local! {
+    static DIRECTIONS: RefCell<DirectionCache> = RefCell::new(HashMap::new());
+}
+
+/// Function that returns every direction a winning line can run in on a board with the given number of dimensions, as a step of -1, 0 or 1 along each axis.
+/// A direction and its reverse give the same lines, so only the one whose last non-zero step is forwards is kept; that leaves right, down-left, down and down-right on a flat board, and 13 directions through a cube.
+pub fn directions(dimensions: usize) -> Rc<[Vec<isize>]> {
+    DIRECTIONS.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(dimensions)
+            .or_insert_with(|| {
+                // Count through every combination of steps along each axis (with the first axis changing fastest)
+                (0..3_usize.pow(dimensions as u32))
+                    .map(|combination: usize| {
+                        (0..dimensions)
+                            .map(|axis: usize| {
+                                (combination / 3_usize.pow(axis as u32) % 3) as isize - 1
+                            })
+                            .collect::<Vec<isize>>()
+                    })
+                    // and keep the ones that move, with their last non-zero step forwards.
+                    .filter(|direction: &Vec<isize>| {
+                        direction.iter().rev().find(|&&step: &&isize| step != 0) == Some(&1)
+                    })
+                    .collect()
+            })
+            .clone()
+    })
+}
+
+/// Function to get every winning combination of length win_length on a board with the given geometry, in any number of dimensions.
+/// This builds every line from scratch; use line_masks() to check for wins, which only builds them once per board.
+pub fn winning_combinations(geometry: Geometry, win_length: usize) -> Vec<Vec<usize>> {
+    let mut combinations: Vec<Vec<usize>> = Vec::new();
+    let directions: Rc<[Vec<isize>]> = directions(geometry.dimensions().len());
+    // For each cell on the board treat the cell as the start of a line
+    for index in 0..geometry.len() {
+        let point: Vec<usize> = geometry.point(index);
+        // and for each direction the line can run in
+        for direction in directions.iter() {
+            // check where the line would end if it were win_length cells long, skipping this direction if that falls off the board.
+            if geometry
+                .step(&point, direction, win_length as isize - 1)
+                .is_none()
+            {
+                continue;
+            }
+            // Otherwise collect the index of every cell in the line.
+            // For example if the cell is (0, 0), the direction is down-right, the board is 3x3 and win_length = 3 the line would be: [0, 4, 8].
+            let line: Vec<usize> = (0..win_length as isize)
+                .filter_map(|steps: isize| geometry.step(&point, direction, steps))
+                .collect();
+            combinations.push(line);
+        }
     }
+    combinations
 }
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// Holds the dimensions of the board and handles converting between (x, y) or (x, y, z) coordinates and board indexes.
+/// Boards with a depth of more than one are three-dimensional, made of depth layers of width x height cells, with layer z following layer z - 1 on the flattened board.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Geometry {
+    pub width: usize,
+    pub height: usize,
+    /// The number of layers; games saved before 3D boards existed have one.
+    #[serde(default = "single_layer")]
+    pub depth: usize,
+}
+
+/// Function that gives the depth of a flat board, for deserializing boards saved without one.
+fn single_layer() -> usize {
+    1
+}
+
+/// Implementation for Geometry containing functions for index math so that no other module has to hard-code it.
+impl Geometry {
+    /// Creates a new flat geometry with the given width and height.
+    pub fn new(width: usize, height: usize) -> Self {
+        Geometry {
+            width,
+            height,
+            depth: 1,
+        }
+    }
+    /// Creates a geometry without any cells, for deserializing games saved before boards had dimensions (load_game() works them out from the board).
+    pub fn empty() -> Self {
+        Geometry::new(0, 0)
+    }
+    /// Returns the same geometry with depth layers.
+    pub fn with_depth(self, depth: usize) -> Self {
+        Geometry { depth, ..self }
+    }
+    /// Returns the number of cells on the board.
+    pub fn len(&self) -> usize {
+        self.width * self.height * self.depth
+    }
+    /// Returns true if the board has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the length of each axis of the board; width and height, followed by depth if the board has more than one layer.
+    pub fn dimensions(&self) -> Vec<usize> {
+        match self.depth {
+            1 => vec![self.width, self.height],
+            depth => vec![self.width, self.height, depth],
+        }
+    }
+    /// Returns true if (x, y) is on a layer of the board.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+    /// Returns true if (x, y, z) is on the board.
+    pub fn contains_3d(&self, x: usize, y: usize, z: usize) -> bool {
+        self.contains(x, y) && z < self.depth
+    }
+    /// Converts (x, y) coordinates on the first layer into an index of the flattened board.
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        self.index_3d(x, y, 0)
+    }
+    /// Converts (x, y, z) coordinates into an index of the flattened board.
+    pub fn index_3d(&self, x: usize, y: usize, z: usize) -> usize {
+        (z * self.height + y) * self.width + x
+    }
+    /// Converts an index of the flattened board back into (x, y) coordinates on its layer.
+    pub fn coords(&self, index: usize) -> (usize, usize) {
+        let (x, y, _) = self.coords_3d(index);
+        (x, y)
+    }
+    /// Converts an index of the flattened board back into (x, y, z) coordinates.
+    pub fn coords_3d(&self, index: usize) -> (usize, usize, usize) {
+        let layer: usize = self.width * self.height;
+        (
+            index % self.width,
+            (index % layer) / self.width,
+            index / layer,
+        )
+    }
+    /// Converts an index of the flattened board into its position along each axis of dimensions().
+    pub fn point(&self, index: usize) -> Vec<usize> {
+        let (x, y, z) = self.coords_3d(index);
+        let mut point: Vec<usize> = vec![x, y, z];
+        point.truncate(self.dimensions().len());
+        point
+    }
+    /// Returns the index of the cell reached by taking steps from point in direction (a step along each axis), if it's on the board.
+    /// Boards have at most three axes (width, height and depth), so point and direction have two entries on a flat board and three on a 3D one; any more are ignored.
+    pub fn step(&self, point: &[usize], direction: &[isize], steps: isize) -> Option<usize> {
+        let axes: [usize; 3] = [self.width, self.height, self.depth];
+        let mut index: usize = 0;
+        let mut stride: usize = 1;
+        for (axis, (&start, &delta)) in point.iter().zip(direction).take(3).enumerate() {
+            let position: isize = start as isize + delta * steps;
+            if position < 0 || position as usize >= axes[axis] {
+                return None;
+            }
+            index += position as usize * stride;
+            stride *= axes[axis];
+        }
+        Some(index)
+    }
+}
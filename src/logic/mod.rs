@@ -1,11 +1,32 @@
+pub mod bitboard;
+pub mod cell;
 pub mod game_error;
+pub mod game_move;
 pub mod game_state;
 pub mod game_status;
 pub mod generate;
+pub mod geometry;
+pub mod notakto;
 pub mod player;
+pub mod quantum;
+pub mod rules;
+pub mod symbol;
+pub mod symmetry;
+pub mod ultimate;
+pub mod zobrist;
 
+pub use bitboard::Bitboard;
+pub use cell::Cell;
 pub use game_error::GameError;
+pub use game_move::Move;
 pub use game_state::GameState;
 pub use game_status::GameStatus;
 pub use generate::Generate;
+pub use geometry::Geometry;
+pub use notakto::NotaktoState;
 pub use player::Player;
+pub use quantum::{QuantumMark, QuantumState};
+pub use rules::{Rules, Variant};
+pub use symbol::Symbol;
+pub use symmetry::Symmetry;
+pub use ultimate::UltimateState;
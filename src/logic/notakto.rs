@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+
+use crate::BoardArgs;
+
+use super::{GameError, GameState, GameStatus, Generate, Move, Player};
+
+/// Number of cells along each side of a Notakto board.
+pub const NOTAKTO_SIZE: usize = 3;
+
+/// Holds the state of a game of Notakto; one or more 3x3 boards that both players mark with X, where a board is dead once it has three in a row and whoever kills the last board loses.
+/// Moves use (x, y) coordinates on a board and the board's index as z, so boards are laid out like the layers of a 3D board.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct NotaktoState {
+    /// The boards, left to right; each is a standard 3x3 game that only ever gets X's, so a board that has been won is dead.
+    pub boards: Vec<GameState>,
+    pub current_player: Player,
+    pub status: GameStatus,
+    /// Every move played so far, in order.
+    pub history: Vec<Move>,
+}
+
+/// Implementation for NotaktoState containing functions to create a game, apply and undo moves, and find out where moves can be played.
+impl NotaktoState {
+    /// Creates a new game on the given number of empty boards, with player X to move.
+    pub fn new(boards: usize) -> Result<Self, Box<dyn std::error::Error>> {
+        if boards == 0 {
+            return Err(Box::new(GameError::InvalidBoardSize));
+        }
+        let board: GameState = GameState::new(&BoardArgs::default())?;
+        Ok(NotaktoState {
+            boards: vec![board; boards],
+            current_player: Player::X,
+            status: GameStatus::Ongoing,
+            history: Vec::new(),
+        })
+    }
+
+    /// Returns true if the board at index has three in a row and can't be played in any more.
+    pub fn is_dead(&self, index: usize) -> bool {
+        self.boards[index].status != GameStatus::Ongoing
+    }
+
+    /// Returns every move the current player could make, or nothing if the game is over.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        if self.status != GameStatus::Ongoing {
+            return Vec::new();
+        }
+        (0..self.boards.len())
+            .filter(|&z: &usize| !self.is_dead(z))
+            .flat_map(|z: usize| {
+                self.boards[z]
+                    .legal_moves()
+                    .into_iter()
+                    .map(move |mv: Move| Move::new(self.current_player, mv.x, mv.y).on_layer(z))
+            })
+            .collect()
+    }
+
+    /// Apply a move, putting an X on the board it's played in and ending the game (with the mover losing) if that kills the last board.
+    /// The move is rejected (and the state left untouched) if the game is over, it's not the mover's turn, it's off the boards, on a dead board, or the cell is occupied.
+    pub fn apply_move(&mut self, mv: Move) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        if self.status != GameStatus::Ongoing {
+            return Err(Box::new(GameError::GameOver));
+        }
+        if mv.player != self.current_player {
+            return Err(Box::new(GameError::NotYourTurn));
+        }
+        if mv.x >= NOTAKTO_SIZE || mv.y >= NOTAKTO_SIZE || mv.z >= self.boards.len() {
+            return Err(Box::new(GameError::OutOfBounds));
+        }
+        if self.is_dead(mv.z) {
+            return Err(Box::new(GameError::BoardFinished));
+        }
+        // Both players mark with X, so play the move on its board as X whoever made it.
+        let board: &mut GameState = &mut self.boards[mv.z];
+        board.current_player = Player::X;
+        board.apply_move(Move::new(Player::X, mv.x, mv.y))?;
+        self.history.push(mv);
+
+        // Killing the last board loses, so the other player wins.
+        if (0..self.boards.len()).all(|z: usize| self.is_dead(z)) {
+            self.status = GameStatus::Won(mv.player.other());
+        } else {
+            self.current_player = mv.player.other();
+        }
+        Ok(self.status.clone())
+    }
+
+    /// Take back the last move, bringing its board back to life if the move killed it.
+    pub fn undo(&mut self) -> Result<Move, Box<dyn std::error::Error>> {
+        let mv: Move = self.history.pop().ok_or(GameError::NothingToUndo)?;
+        self.boards[mv.z].undo()?;
+        // The move was played while the game was ongoing, so taking it back always leaves an ongoing game.
+        self.status = GameStatus::Ongoing;
+        self.current_player = mv.player;
+        Ok(mv)
+    }
+}
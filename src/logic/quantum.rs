@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use super::{generate::winning_combinations, GameError, GameStatus, Geometry, Player};
+
+/// Number of cells along each side of a quantum tic-tac-toe board.
+pub const QUANTUM_SIZE: usize = 3;
+
+/// Holds one mark of a game of quantum tic-tac-toe; a spooky mark in two cells until a collapse makes it a classical mark in one of them.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct QuantumMark {
+    pub player: Player,
+    /// The move the mark was placed on, counting from 1; shown as its subscript, so X1 is the first move of the game.
+    pub turn: usize,
+    /// The indexes of the two cells the mark is in; both the same for a classical mark played in the last free cell.
+    pub cells: [usize; 2],
+    /// The index of the cell the mark ended up in, or None while it's still spooky.
+    pub collapsed: Option<usize>,
+}
+
+/// Implementation for QuantumMark containing a function to find the other end of a spooky mark.
+impl QuantumMark {
+    /// Returns the cell at the other end of the mark from cell.
+    pub fn other_cell(&self, cell: usize) -> usize {
+        if self.cells[0] == cell {
+            self.cells[1]
+        } else {
+            self.cells[0]
+        }
+    }
+}
+
+/// Implementation of std::fmt::Display for QuantumMark to display it as its player and subscript (e.g. "X1").
+impl std::fmt::Display for QuantumMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.player, self.turn)
+    }
+}
+
+/// Holds the state of a game of quantum tic-tac-toe on a 3x3 board.
+/// Every move puts a spooky mark in two cells, joining them in the entanglement graph (the cells are its nodes and the spooky marks its edges).
+/// A mark that closes a cycle in the graph forces a collapse; the other player picks which of its two cells the mark ends up in, which settles every other mark joined to it, and then takes their turn.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QuantumState {
+    pub geometry: Geometry,
+    /// Every mark played so far, in order.
+    pub marks: Vec<QuantumMark>,
+    /// The player to move, or to pick the collapse if one is pending.
+    pub current_player: Player,
+    pub status: GameStatus,
+    /// The index in marks of the mark that closed a cycle, while the current player still has to pick where it collapses.
+    pub pending_collapse: Option<usize>,
+    /// Every line of classical marks once the game has ended, with its player and the highest subscript in it, earliest completed first.
+    pub winning_lines: Vec<(Player, usize, Vec<usize>)>,
+}
+
+/// Implementation for QuantumState containing functions to create a game, play spooky marks, collapse cycles and score the game.
+impl QuantumState {
+    /// Creates a new game on an empty board, with X to move.
+    pub fn new() -> Self {
+        QuantumState {
+            geometry: Geometry::new(QUANTUM_SIZE, QUANTUM_SIZE),
+            marks: Vec::new(),
+            current_player: Player::X,
+            status: GameStatus::Ongoing,
+            pending_collapse: None,
+            winning_lines: Vec::new(),
+        }
+    }
+
+    /// Returns the classical mark in the cell at index, if it has collapsed into one.
+    pub fn classical(&self, index: usize) -> Option<&QuantumMark> {
+        self.marks
+            .iter()
+            .find(|mark: &&QuantumMark| mark.collapsed == Some(index))
+    }
+
+    /// Returns the spooky marks in the cell at index, in the order they were played.
+    pub fn spooky(&self, index: usize) -> Vec<&QuantumMark> {
+        self.marks
+            .iter()
+            .filter(|mark: &&QuantumMark| mark.collapsed.is_none() && mark.cells.contains(&index))
+            .collect()
+    }
+
+    /// Returns the indexes of the cells without a classical mark, which are the ones spooky marks can be played in.
+    pub fn free_cells(&self) -> Vec<usize> {
+        (0..self.geometry.len())
+            .filter(|&index: &usize| self.classical(index).is_none())
+            .collect()
+    }
+
+    /// Returns true if (x, y) is part of a line that ended the game.
+    pub fn is_winning_cell(&self, x: usize, y: usize) -> bool {
+        let index: usize = self.geometry.index(x, y);
+        self.winning_lines
+            .iter()
+            .any(|(_, _, line): &(Player, usize, Vec<usize>)| line.contains(&index))
+    }
+
+    /// Play a spooky mark for player in the cells first and second, given as (x, y).
+    /// Once only one cell is left without a classical mark, the last move is a classical mark there, played with first and second both that cell.
+    /// The move is rejected (and the state left untouched) if the game is over, a collapse is pending, it's not the mover's turn, or the cells are off the board, classical or the same cell when they can't be.
+    pub fn apply_move(
+        &mut self,
+        player: Player,
+        first: (usize, usize),
+        second: (usize, usize),
+    ) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        if self.status != GameStatus::Ongoing {
+            return Err(Box::new(GameError::GameOver));
+        }
+        if self.pending_collapse.is_some() {
+            return Err(Box::new(GameError::CollapsePending));
+        }
+        if player != self.current_player {
+            return Err(Box::new(GameError::NotYourTurn));
+        }
+        if !self.geometry.contains(first.0, first.1) || !self.geometry.contains(second.0, second.1)
+        {
+            return Err(Box::new(GameError::OutOfBounds));
+        }
+        let cells: [usize; 2] = [
+            self.geometry.index(first.0, first.1),
+            self.geometry.index(second.0, second.1),
+        ];
+        let free_cells: Vec<usize> = self.free_cells();
+        if !cells.iter().all(|cell: &usize| free_cells.contains(cell)) {
+            return Err(Box::new(GameError::CellOccupied));
+        }
+        // Both halves of a mark have to be in different cells, unless there is only one cell left to play.
+        if (cells[0] == cells[1]) != (free_cells.len() == 1) {
+            return Err(Box::new(GameError::SameCell));
+        }
+
+        // Check for a cycle before adding the mark, since it closes one if its cells are already joined.
+        let closes_cycle: bool = cells[0] != cells[1] && self.is_entangled(cells[0], cells[1]);
+        let mark: QuantumMark = QuantumMark {
+            player,
+            turn: self.marks.len() + 1,
+            cells,
+            collapsed: None,
+        };
+        self.marks.push(mark);
+        self.current_player = player.other();
+        if closes_cycle {
+            // The other player picks where the mark collapses before they move.
+            self.pending_collapse = Some(self.marks.len() - 1);
+        } else if cells[0] == cells[1] {
+            // The last mark of the game is classical straight away, so it can complete a line.
+            self.collapse_into(self.marks.len() - 1, cells[0]);
+            self.update_status();
+        }
+        Ok(self.status.clone())
+    }
+
+    /// Collapse the cycle closed by the last mark, with the mark ending up in the cell at (x, y), for player (who has to be the one who didn't close the cycle).
+    /// Every mark joined to it is settled in turn; a mark sharing a cell with a classical one is pushed into its other cell.
+    /// The collapse is rejected if there is nothing to collapse, it's not the player's turn, or (x, y) isn't one of the mark's two cells.
+    pub fn collapse(
+        &mut self,
+        player: Player,
+        (x, y): (usize, usize),
+    ) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        let mark: usize = self.pending_collapse.ok_or(GameError::NothingToCollapse)?;
+        if player != self.current_player {
+            return Err(Box::new(GameError::NotYourTurn));
+        }
+        if !self.geometry.contains(x, y) {
+            return Err(Box::new(GameError::OutOfBounds));
+        }
+        let cell: usize = self.geometry.index(x, y);
+        if !self.marks[mark].cells.contains(&cell) {
+            return Err(Box::new(GameError::InvalidCollapse));
+        }
+        self.pending_collapse = None;
+        self.collapse_into(mark, cell);
+        self.update_status();
+        Ok(self.status.clone())
+    }
+
+    /// Returns true if the cells at from and to are joined by a chain of spooky marks, found with a breadth-first search of the entanglement graph.
+    pub fn is_entangled(&self, from: usize, to: usize) -> bool {
+        let mut visited: Vec<bool> = vec![false; self.geometry.len()];
+        let mut queue: VecDeque<usize> = VecDeque::from([from]);
+        visited[from] = true;
+        while let Some(cell) = queue.pop_front() {
+            if cell == to {
+                return true;
+            }
+            for mark in self.spooky(cell) {
+                let next: usize = mark.other_cell(cell);
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Makes the mark at index in marks classical in cell, then pushes every other spooky mark in that cell into its other cell, and so on until the whole entangled group has settled.
+    fn collapse_into(&mut self, mark: usize, cell: usize) {
+        let mut settling: Vec<(usize, usize)> = vec![(mark, cell)];
+        while let Some((mark, cell)) = settling.pop() {
+            if self.marks[mark].collapsed.is_some() {
+                continue;
+            }
+            self.marks[mark].collapsed = Some(cell);
+            for (other, other_mark) in self.marks.iter().enumerate() {
+                if other_mark.collapsed.is_none() && other_mark.cells.contains(&cell) {
+                    settling.push((other, other_mark.other_cell(cell)));
+                }
+            }
+        }
+    }
+
+    /// Ends the game if a collapse completed any lines of classical marks, or left no room for another spooky mark.
+    fn update_status(&mut self) {
+        let mut lines: Vec<(Player, usize, Vec<usize>)> =
+            winning_combinations(self.geometry, QUANTUM_SIZE)
+                .into_iter()
+                .filter_map(|line: Vec<usize>| {
+                    let marks: Vec<&QuantumMark> = line
+                        .iter()
+                        .map(|&cell: &usize| self.classical(cell))
+                        .collect::<Option<Vec<&QuantumMark>>>()?;
+                    let player: Player = marks[0].player;
+                    if !marks
+                        .iter()
+                        .all(|mark: &&QuantumMark| mark.player == player)
+                    {
+                        return None;
+                    }
+                    let completed: usize = marks
+                        .iter()
+                        .map(|mark: &&QuantumMark| mark.turn)
+                        .max()
+                        .unwrap_or(0);
+                    Some((player, completed, line))
+                })
+                .collect();
+        lines.sort_by_key(|(_, completed, _): &(Player, usize, Vec<usize>)| *completed);
+        self.status = match lines.first() {
+            // The line completed with the earliest marks wins, even if the collapse gave the other player one too.
+            Some((player, _, _)) => GameStatus::Won(*player),
+            None if self.free_cells().is_empty() => GameStatus::Draw,
+            None => GameStatus::Ongoing,
+        };
+        self.winning_lines = lines;
+    }
+
+    /// Returns the points player scored once the game is over; the player whose line has the lowest highest subscript scores 1,
+    /// and if the same collapse gave the other player a line too, they score ½.
+    pub fn points(&self, player: Player) -> f32 {
+        match self
+            .winning_lines
+            .iter()
+            .position(|(line_player, _, _): &(Player, usize, Vec<usize>)| *line_player == player)
+        {
+            Some(0) => 1.0,
+            Some(_) => 0.5,
+            None => 0.0,
+        }
+    }
+}
+
+/// Implementation of Default for QuantumState that gives a new game.
+impl Default for QuantumState {
+    fn default() -> Self {
+        QuantumState::new()
+    }
+}
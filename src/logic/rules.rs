@@ -0,0 +1,180 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{Cell, GameStatus, Geometry, Player, Symmetry};
+
+/// The number a line has to add up to in numerical tic-tac-toe.
+pub const NUMERICAL_TARGET: u32 = 15;
+
+/// Trait for the rules that decide what completing a line means, so that variants can change the goal of the game without changing how lines are found.
+pub trait Rules {
+    /// Returns the status of the game once owner has completed a line.
+    fn completed_line(&self, owner: Player) -> GameStatus;
+    /// Returns true if marks fall to the lowest empty cell of the column they're played in.
+    fn drops_marks(&self) -> bool;
+    /// Returns the board (and win length) the rules are played on when no size is given, or None to use the usual 3x3 board.
+    fn default_board(&self) -> Option<(Geometry, usize)>;
+    /// Returns every symmetry of the board that turns a position into an equivalent one under these rules.
+    fn symmetries(&self, geometry: Geometry) -> Vec<Symmetry>;
+    /// Returns true if the player to move picks which symbol to mark with, rather than always marking with their own.
+    fn chooses_symbols(&self) -> bool;
+    /// Returns the numbers player can play, or nothing if the rules have the players mark with symbols.
+    fn numbers(&self, player: Player) -> Vec<u8>;
+    /// Returns true if the players play numbers rather than marking with symbols.
+    fn uses_numbers(&self) -> bool;
+    /// Returns true if the cells of a line of numbers, in order, make a completed line.
+    /// Lines of symbols are runs of one symbol, which are found from the marks themselves (with bitboards or by walking the run) rather than through here.
+    fn completes_line(&self, cells: &[Cell]) -> bool;
+    /// Returns the player a completed line whose first cell is first counts for, given the player whose move completed it.
+    fn line_owner(&self, first: Cell, mover: Player) -> Player;
+    /// Returns true if a line only counts when it isn't part of a longer run of the same symbol.
+    fn exact_lines(&self) -> bool;
+    /// Returns the status of the game once the board has been filled without a line being completed.
+    fn board_filled(&self) -> GameStatus;
+    /// Returns the name of the role player plays, for rules where the two sides have different goals.
+    fn role(&self, player: Player) -> Option<&'static str>;
+}
+
+/// Enum to represent the rule variants the game can be played with.
+#[derive(Serialize, Deserialize, ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Completing a line wins.
+    #[default]
+    Standard,
+    /// Completing a line loses (avoidance tic-tac-toe).
+    Misere,
+    /// Marks fall to the lowest empty cell of their column; Connect Four on its default 7x6 board with 4 in a row.
+    Gravity,
+    /// Each player picks X or O on every move, and whoever completes a line of either wins.
+    Wild,
+    /// Order (X, moving first) wins by completing a line of exactly five of either symbol, and Chaos (O) wins by filling the board without that happening; both place either symbol, on a 6x6 board by default.
+    OrderChaos,
+    /// X plays the odd numbers from 1 to 9 and O the even ones, each number at most once, and whoever completes a line adding up to 15 wins; on a 3x3 board only.
+    Numerical,
+}
+
+/// Implementation for Variant containing every variant, so that they can be listed, and a description of the roles the players take.
+impl Variant {
+    /// Describes which role each player plays (e.g. "X plays Order, O plays Chaos"), or None if the rules don't give them roles.
+    pub fn describe_roles(&self) -> Option<String> {
+        let x: &str = self.role(Player::X)?;
+        let o: &str = self.role(Player::O)?;
+        Some(format!("X plays {}, O plays {}", x, o))
+    }
+
+    /// Every variant, starting with the standard rules.
+    pub const ALL: [Variant; 6] = [
+        Variant::Standard,
+        Variant::Misere,
+        Variant::Gravity,
+        Variant::Wild,
+        Variant::OrderChaos,
+        Variant::Numerical,
+    ];
+}
+
+/// Implementation of 'Rules' for Variant that hands the game to whoever completed a line (or to their opponent in misère), drops marks in gravity, lets players pick their symbol in wild and Order and Chaos, gives Order and Chaos its roles, and plays numbers in numerical.
+impl Rules for Variant {
+    fn completed_line(&self, owner: Player) -> GameStatus {
+        match self {
+            Variant::Standard
+            | Variant::Gravity
+            | Variant::Wild
+            | Variant::OrderChaos
+            | Variant::Numerical => GameStatus::Won(owner),
+            Variant::Misere => GameStatus::Won(owner.other()),
+        }
+    }
+    fn drops_marks(&self) -> bool {
+        *self == Variant::Gravity
+    }
+    fn default_board(&self) -> Option<(Geometry, usize)> {
+        match self {
+            Variant::Gravity => Some((Geometry::new(7, 6), 4)),
+            Variant::OrderChaos => Some((Geometry::new(6, 6), 5)),
+            Variant::Numerical => Some((Geometry::new(3, 3), 3)),
+            _ => None,
+        }
+    }
+    fn symmetries(&self, geometry: Geometry) -> Vec<Symmetry> {
+        match self {
+            // Marks fall down the board, so only mirroring it left to right keeps a position the same.
+            Variant::Gravity => vec![Symmetry::Identity, Symmetry::FlipHorizontal],
+            _ => Symmetry::all_for(geometry),
+        }
+    }
+    fn chooses_symbols(&self) -> bool {
+        matches!(self, Variant::Wild | Variant::OrderChaos)
+    }
+    fn numbers(&self, player: Player) -> Vec<u8> {
+        match (self, player) {
+            (Variant::Numerical, Player::X) => vec![1, 3, 5, 7, 9],
+            (Variant::Numerical, Player::O) => vec![2, 4, 6, 8],
+            _ => Vec::new(),
+        }
+    }
+    fn uses_numbers(&self) -> bool {
+        *self == Variant::Numerical
+    }
+    fn completes_line(&self, cells: &[Cell]) -> bool {
+        match self {
+            // A line of numbers (whoever played them) is complete once they add up to the target.
+            Variant::Numerical => {
+                cells
+                    .iter()
+                    .map(|cell: &Cell| match cell {
+                        Cell::Number(number) => Some(u32::from(*number)),
+                        _ => None,
+                    })
+                    .sum::<Option<u32>>()
+                    == Some(NUMERICAL_TARGET)
+            }
+            // Other rules don't have numbers, so none of their lines are lines of numbers.
+            _ => false,
+        }
+    }
+    fn line_owner(&self, first: Cell, mover: Player) -> Player {
+        match (self, first) {
+            // Either player can complete a line of either symbol, so it belongs to whoever did.
+            (Variant::Wild, _) => mover,
+            // Every line is Order's, even one Chaos was forced to complete.
+            (Variant::OrderChaos, _) => Player::X,
+            // Otherwise each symbol is only ever played by one player, so the line is theirs,
+            (_, Cell::Occupied(symbol)) => Player::from(symbol),
+            // and a line of numbers (which can mix both players' numbers) belongs to whoever completed it.
+            _ => mover,
+        }
+    }
+    fn exact_lines(&self) -> bool {
+        // Six in a row doesn't win Order and Chaos.
+        *self == Variant::OrderChaos
+    }
+    fn board_filled(&self) -> GameStatus {
+        match self {
+            // Chaos's goal is to fill the board without Order getting a line.
+            Variant::OrderChaos => GameStatus::Won(Player::O),
+            _ => GameStatus::Draw,
+        }
+    }
+    fn role(&self, player: Player) -> Option<&'static str> {
+        match (self, player) {
+            (Variant::OrderChaos, Player::X) => Some("Order"),
+            (Variant::OrderChaos, Player::O) => Some("Chaos"),
+            _ => None,
+        }
+    }
+}
+
+/// Implementation to display variant as a string ("standard", "misere", "gravity", "wild", "order-chaos" or "numerical"), the same as it's written on the command line.
+impl std::fmt::Display for Variant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Variant::Standard => write!(f, "standard"),
+            Variant::Misere => write!(f, "misere"),
+            Variant::Gravity => write!(f, "gravity"),
+            Variant::Wild => write!(f, "wild"),
+            Variant::OrderChaos => write!(f, "order-chaos"),
+            Variant::Numerical => write!(f, "numerical"),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::Player;
+
+/// Enum to represent the symbol a cell is marked with: either X or O.
+/// Each player usually marks with the symbol of the same name, but in wild tic-tac-toe they pick either symbol on every move.
+#[derive(Serialize, Deserialize, ValueEnum, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    X,
+    O,
+}
+
+/// Implementation to display symbol as a string ("X" or "O").
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Symbol::X => write!(f, "X"),
+            Symbol::O => write!(f, "O"),
+        }
+    }
+}
+
+/// Implementation to return the other symbol.
+impl Symbol {
+    pub fn other(self) -> Self {
+        match self {
+            Symbol::X => Symbol::O,
+            Symbol::O => Symbol::X,
+        }
+    }
+}
+
+/// Implementation to get the symbol a player marks with when they don't get to choose.
+impl From<Player> for Symbol {
+    fn from(player: Player) -> Self {
+        match player {
+            Player::X => Symbol::X,
+            Player::O => Symbol::O,
+        }
+    }
+}
+
+/// Implementation to get the player who marks with a symbol when players don't get to choose.
+impl From<Symbol> for Player {
+    fn from(symbol: Symbol) -> Self {
+        match symbol {
+            Symbol::X => Player::X,
+            Symbol::O => Player::O,
+        }
+    }
+}
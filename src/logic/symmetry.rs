@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use super::Geometry;
+
+/// Enum to represent the eight symmetries of a square board (the dihedral group of the square).
+/// Boards that aren't square only have the four that keep their width and height: Identity, RotateHalf, FlipHorizontal and FlipVertical.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// A quarter turn clockwise.
+    RotateQuarter,
+    RotateHalf,
+    /// Three quarter turns clockwise (a quarter turn anticlockwise).
+    RotateThreeQuarter,
+    /// Mirror left to right.
+    FlipHorizontal,
+    /// Mirror top to bottom.
+    FlipVertical,
+    /// Mirror across the diagonal from the top left to the bottom right.
+    FlipDiagonal,
+    /// Mirror across the diagonal from the top right to the bottom left.
+    FlipAntiDiagonal,
+}
+
+/// Implementation for Symmetry containing functions to list the symmetries of a board and to move cells with them.
+impl Symmetry {
+    /// Every symmetry of a square board, starting with the identity.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateQuarter,
+        Symmetry::RotateHalf,
+        Symmetry::RotateThreeQuarter,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+
+    /// Returns every symmetry of a board with the given geometry, starting with the identity.
+    pub fn all_for(geometry: Geometry) -> Vec<Symmetry> {
+        Symmetry::ALL
+            .into_iter()
+            .filter(|symmetry: &Symmetry| symmetry.fits(geometry))
+            .collect()
+    }
+
+    /// Returns true if the symmetry maps a board with the given geometry onto itself; anything that swaps rows and columns needs a square board.
+    pub fn fits(self, geometry: Geometry) -> bool {
+        match self {
+            Symmetry::Identity
+            | Symmetry::RotateHalf
+            | Symmetry::FlipHorizontal
+            | Symmetry::FlipVertical => true,
+            _ => geometry.width == geometry.height,
+        }
+    }
+
+    /// Returns the symmetry that undoes this one.
+    pub fn inverse(self) -> Self {
+        match self {
+            Symmetry::RotateQuarter => Symmetry::RotateThreeQuarter,
+            Symmetry::RotateThreeQuarter => Symmetry::RotateQuarter,
+            symmetry => symmetry,
+        }
+    }
+
+    /// Returns where (x, y) ends up when the symmetry is applied to a board with the given geometry.
+    pub fn apply(self, geometry: Geometry, x: usize, y: usize) -> (usize, usize) {
+        let (right, bottom) = (geometry.width - 1, geometry.height - 1);
+        match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::RotateQuarter => (bottom - y, x),
+            Symmetry::RotateHalf => (right - x, bottom - y),
+            Symmetry::RotateThreeQuarter => (y, right - x),
+            Symmetry::FlipHorizontal => (right - x, y),
+            Symmetry::FlipVertical => (x, bottom - y),
+            Symmetry::FlipDiagonal => (y, x),
+            Symmetry::FlipAntiDiagonal => (bottom - y, right - x),
+        }
+    }
+
+    /// Returns the board index a cell moves to when the symmetry is applied.
+    pub fn apply_index(self, geometry: Geometry, index: usize) -> usize {
+        let (x, y, z) = geometry.coords_3d(index);
+        let (x, y) = self.apply(geometry, x, y);
+        geometry.index_3d(x, y, z)
+    }
+}
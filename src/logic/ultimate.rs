@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+
+use crate::BoardArgs;
+
+use super::{Cell, GameError, GameState, GameStatus, Generate, Move, Player};
+
+/// Number of small boards along each side of the meta-board, and cells along each side of a small board.
+pub const ULTIMATE_SIZE: usize = 3;
+
+/// Number of cells along each side of the whole grid.
+pub const ULTIMATE_CELLS: usize = ULTIMATE_SIZE * ULTIMATE_SIZE;
+
+/// Holds the state of a game of Ultimate tic-tac-toe; nine small boards arranged on a 3x3 meta-board.
+/// Moves use (x, y) coordinates on the whole 9x9 grid; the small board a move is in is (x / 3, y / 3), and the cell it's in on that board, (x % 3, y % 3), is the board the next player has to play in.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UltimateState {
+    /// The small boards, left to right and top to bottom.
+    pub boards: Vec<GameState>,
+    /// The meta-board, with each cell marked by whoever won the small board in that position; its status is decided by check_winner like any 3x3 game.
+    pub meta: GameState,
+    pub current_player: Player,
+    pub status: GameStatus,
+    /// The index of the small board the current player has to play in, or None if they can play in any board that isn't finished.
+    pub forced_board: Option<usize>,
+    /// Every move played so far, in order.
+    pub history: Vec<Move>,
+}
+
+/// Implementation for UltimateState containing functions to create a game, apply and undo moves, and find out where moves can be played.
+impl UltimateState {
+    /// Creates a new game, with every board empty and X free to play anywhere.
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let board: GameState = GameState::new(&BoardArgs::default())?;
+        Ok(UltimateState {
+            boards: vec![board.clone(); ULTIMATE_SIZE * ULTIMATE_SIZE],
+            meta: board,
+            current_player: Player::X,
+            status: GameStatus::Ongoing,
+            forced_board: None,
+            history: Vec::new(),
+        })
+    }
+
+    /// Returns the index of the small board (x, y) on the whole grid is in.
+    pub fn board_index(x: usize, y: usize) -> usize {
+        (y / ULTIMATE_SIZE) * ULTIMATE_SIZE + x / ULTIMATE_SIZE
+    }
+
+    /// Returns the cell at (x, y) on the whole grid.
+    pub fn cell(&self, x: usize, y: usize) -> Cell {
+        let board: &GameState = &self.boards[UltimateState::board_index(x, y)];
+        board.board[board.geometry.index(x % ULTIMATE_SIZE, y % ULTIMATE_SIZE)]
+    }
+
+    /// Returns true if the current player can play in the small board at index; it has to be unfinished, and the forced board if there is one.
+    pub fn is_playable_board(&self, index: usize) -> bool {
+        self.status == GameStatus::Ongoing
+            && self.boards[index].status == GameStatus::Ongoing
+            && self
+                .forced_board
+                .is_none_or(|forced: usize| forced == index)
+    }
+
+    /// Returns every move the current player could make, or nothing if the game is over.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        (0..ULTIMATE_CELLS)
+            .flat_map(|y: usize| (0..ULTIMATE_CELLS).map(move |x: usize| (x, y)))
+            .filter(|&(x, y): &(usize, usize)| {
+                self.is_playable_board(UltimateState::board_index(x, y))
+                    && self.cell(x, y) == Cell::Empty
+            })
+            .map(|(x, y): (usize, usize)| Move::new(self.current_player, x, y))
+            .collect()
+    }
+
+    /// Apply a move, updating the small board it's played in, the meta-board and the game status, and sending the other player to the board matching the cell played.
+    /// The move is rejected (and the state left untouched) if the game is over, it's not the mover's turn, it's off the grid, in the wrong or a finished board, or the cell is occupied.
+    pub fn apply_move(&mut self, mv: Move) -> Result<GameStatus, Box<dyn std::error::Error>> {
+        if self.status != GameStatus::Ongoing {
+            return Err(Box::new(GameError::GameOver));
+        }
+        if mv.player != self.current_player {
+            return Err(Box::new(GameError::NotYourTurn));
+        }
+        if mv.x >= ULTIMATE_CELLS || mv.y >= ULTIMATE_CELLS {
+            return Err(Box::new(GameError::OutOfBounds));
+        }
+        let index: usize = UltimateState::board_index(mv.x, mv.y);
+        if self.boards[index].status != GameStatus::Ongoing {
+            return Err(Box::new(GameError::BoardFinished));
+        }
+        if self
+            .forced_board
+            .is_some_and(|forced: usize| forced != index)
+        {
+            return Err(Box::new(GameError::WrongBoard));
+        }
+        // Play the move on the small board, as whoever's turn it is on the whole grid.
+        let (x, y) = (mv.x % ULTIMATE_SIZE, mv.y % ULTIMATE_SIZE);
+        let board: &mut GameState = &mut self.boards[index];
+        board.current_player = mv.player;
+        let board_status: GameStatus = board.apply_move(Move::new(mv.player, x, y))?;
+        self.history.push(mv);
+
+        // Winning a small board marks its cell on the meta-board, which is then checked like any other 3x3 game.
+        if let GameStatus::Won(winner) = board_status {
+            self.meta.board[index] = Cell::Occupied(winner.into());
+            self.meta.recount();
+            (self.meta.status, self.meta.winning_lines) = GameState::check_winner(&self.meta)?;
+        }
+        // Drawn boards leave their meta-board cell empty, so the game is also drawn once every board is finished without a winner.
+        self.status = match self.meta.status {
+            GameStatus::Won(winner) => GameStatus::Won(winner),
+            _ if self
+                .boards
+                .iter()
+                .all(|board: &GameState| board.status != GameStatus::Ongoing) =>
+            {
+                GameStatus::Draw
+            }
+            _ => GameStatus::Ongoing,
+        };
+        // The cell played picks the next board, unless that board is finished, in which case the next player can play anywhere.
+        let next: usize = y * ULTIMATE_SIZE + x;
+        self.forced_board = (self.boards[next].status == GameStatus::Ongoing).then_some(next);
+        if self.status == GameStatus::Ongoing {
+            self.current_player = mv.player.other();
+        }
+        Ok(self.status.clone())
+    }
+
+    /// Take back the last move by replaying every move before it on a new game, since the board a move forced can't be worked out from the move alone.
+    pub fn undo(&mut self) -> Result<Move, Box<dyn std::error::Error>> {
+        let mv: Move = *self.history.last().ok_or(GameError::NothingToUndo)?;
+        let mut replayed: UltimateState = UltimateState::new()?;
+        for &earlier in &self.history[..self.history.len() - 1] {
+            replayed.apply_move(earlier)?;
+        }
+        *self = replayed;
+        Ok(mv)
+    }
+}
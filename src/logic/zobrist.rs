@@ -0,0 +1,109 @@
+use super::{Cell, GameState, Geometry, Player, Rules, Symbol, Symmetry, Variant};
+
+/// Function that scrambles a number into a well-mixed 64 bit value (the SplitMix64 finaliser).
+/// Keys are derived from it rather than drawn at random so that hashes are the same on every run, and can be stored in opening books and game databases.
+fn mix(value: u64) -> u64 {
+    let mut z: u64 = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns the Zobrist key of the mark in cell at index (an empty cell adds nothing to the hash).
+/// Numbers have their keys drawn from the other end of the range from the symbols', so the keys of the symbols stay the same as they were before numbers.
+pub fn cell_key(index: usize, cell: Cell) -> u64 {
+    match cell {
+        Cell::Empty => 0,
+        Cell::Occupied(Symbol::X) => mix((index as u64) << 1),
+        Cell::Occupied(Symbol::O) => mix(((index as u64) << 1) | 1),
+        Cell::Number(number) => mix(!(((index as u64) << 4) | u64::from(number))),
+    }
+}
+
+/// Returns the Zobrist key added when it's O's turn.
+pub fn side_key() -> u64 {
+    mix(u64::MAX)
+}
+
+/// Returns the Zobrist key of the game being played, so that the same marks on different boards (or with a different win length or rules) hash differently.
+/// The depth is mixed in last, so that the keys of flat boards stay the same as they were before boards had layers.
+pub fn game_key(geometry: Geometry, win_length: usize, variant: Variant) -> u64 {
+    let key: u64 = mix(mix(
+        mix(mix(geometry.width as u64) ^ geometry.height as u64) ^ win_length as u64
+    ) ^ variant as u64);
+    match geometry.depth {
+        1 => key,
+        depth => mix(key ^ depth as u64),
+    }
+}
+
+/// Implementation for GameState containing functions to identify positions by their Zobrist hash.
+impl GameState {
+    /// Returns the Zobrist hash of the position; the game key, the key of every mark on the board and the side key if it's O's turn, combined with xor.
+    /// It's worked out from the whole board every time it's asked for, rather than kept up to date as moves are played.
+    pub fn zobrist_hash(&self) -> u64 {
+        self.symmetric_hash(Symmetry::Identity)
+    }
+
+    /// Returns the Zobrist hash the position would have after applying the symmetry to the board.
+    pub fn symmetric_hash(&self, symmetry: Symmetry) -> u64 {
+        let mut hash: u64 = game_key(self.geometry, self.win_length, self.variant);
+        if self.current_player == Player::O {
+            hash ^= side_key();
+        }
+        for (index, cell) in self.board.iter().enumerate() {
+            hash ^= cell_key(symmetry.apply_index(self.geometry, index), *cell);
+        }
+        hash
+    }
+
+    /// Returns the symmetry that takes the position to its canonical form; the symmetric position with the smallest hash.
+    /// Every position that's the same up to a rotation or reflection (that the rules allow) has the same canonical form.
+    pub fn canonical_symmetry(&self) -> Symmetry {
+        self.variant
+            .symmetries(self.geometry)
+            .into_iter()
+            .min_by_key(|&symmetry: &Symmetry| self.symmetric_hash(symmetry))
+            .unwrap_or(Symmetry::Identity)
+    }
+
+    /// Returns the hash of the position's canonical form, which is shared by every position that's the same up to a rotation or reflection.
+    pub fn canonical_hash(&self) -> u64 {
+        self.variant
+            .symmetries(self.geometry)
+            .into_iter()
+            .map(|symmetry: Symmetry| self.symmetric_hash(symmetry))
+            .min()
+            .unwrap_or_else(|| self.zobrist_hash())
+    }
+
+    /// Returns the board as it would be after applying the symmetry.
+    pub fn symmetric_board(&self, symmetry: Symmetry) -> Vec<Cell> {
+        let mut board: Vec<Cell> = vec![Cell::Empty; self.board.len()];
+        for (index, cell) in self.board.iter().enumerate() {
+            board[symmetry.apply_index(self.geometry, index)] = *cell;
+        }
+        board
+    }
+
+    /// Returns a copy of the position with the symmetry applied to the board, its history and its winning lines.
+    pub fn transformed(&self, symmetry: Symmetry) -> GameState {
+        let mut state: GameState = self.clone();
+        state.board = self.symmetric_board(symmetry);
+        state.recount();
+        for mv in state.history.iter_mut().chain(state.undone.iter_mut()) {
+            (mv.x, mv.y) = symmetry.apply(self.geometry, mv.x, mv.y);
+        }
+        for cell in state.winning_lines.iter_mut().flatten() {
+            let (x, y) = symmetry.apply(self.geometry, cell.0, cell.1);
+            *cell = (x, y, cell.2);
+        }
+        state
+    }
+
+    /// Returns the canonical form of the position, along with the symmetry that takes it there (whose inverse takes moves found on the canonical form back to this position).
+    pub fn canonical(&self) -> (GameState, Symmetry) {
+        let symmetry: Symmetry = self.canonical_symmetry();
+        (self.transformed(symmetry), symmetry)
+    }
+}
@@ -1,6 +1,9 @@
 use clap::Parser;
 use dioxus::{desktop::Config, prelude::LaunchBuilder};
-use tic_tac_toe_rs::{completions, run_game, App, BoardArgs, TicTacToeArgs, TicTacToeSubcommand};
+use tic_tac_toe_rs::{
+    completions, run_engine, run_game, run_notakto, run_quantum, run_solve, run_tournament,
+    run_ultimate, App, BoardArgs, TicTacToeArgs, TicTacToeSubcommand,
+};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: TicTacToeArgs = TicTacToeArgs::parse();
@@ -8,7 +11,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         match app {
             true => {
                 env_logger::init();
+                // Pass the board arguments (if any) through to the app, otherwise default to a 3x3 board.
+                let board_args: BoardArgs = match args.command {
+                    Some(TicTacToeSubcommand::Board(board_args)) => board_args,
+                    _ => BoardArgs::default(),
+                };
                 LaunchBuilder::desktop()
+                    .with_context(board_args)
                     .with_cfg(
                         Config::new()
                             .with_background_color((30, 30, 46, 255))
@@ -36,9 +45,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Some(command) => match command {
                     TicTacToeSubcommand::Generate(args) => completions(args),
                     TicTacToeSubcommand::Board(args) => run_game(args),
+                    TicTacToeSubcommand::Engine(args) => run_engine(args),
+                    TicTacToeSubcommand::Tournament(args) => run_tournament(args),
+                    TicTacToeSubcommand::Solve(args) => run_solve(args),
+                    TicTacToeSubcommand::Ultimate => run_ultimate(),
+                    TicTacToeSubcommand::Notakto(args) => run_notakto(args),
+                    TicTacToeSubcommand::Quantum => run_quantum(),
                 },
                 None => {
-                    let default = run_game(&BoardArgs { size: Some(3) });
+                    let default = run_game(&BoardArgs::default());
                     return default;
                 }
             },
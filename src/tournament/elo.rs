@@ -0,0 +1,49 @@
+/// Holds an Elo rating difference estimated from a set of results, and the margin of its 95% confidence interval.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Elo {
+    pub rating: f64,
+    pub margin: f64,
+}
+
+/// Implementation for Elo containing the estimate.
+impl Elo {
+    /// Estimates the rating difference against the opponents played from the wins, draws and losses against them, or None if no games were played.
+    /// A perfect (or perfectly bad) score gives an infinite rating.
+    pub fn from_results(wins: u32, draws: u32, losses: u32) -> Option<Self> {
+        let games: f64 = f64::from(wins + draws + losses);
+        if games == 0.0 {
+            return None;
+        }
+        // Average score per game, counting a draw as half a win.
+        let score: f64 = (f64::from(wins) + 0.5 * f64::from(draws)) / games;
+        // Spread of the per-game scores around the average, giving the standard error of the average.
+        let variance: f64 = (f64::from(wins) * (1.0 - score).powi(2)
+            + f64::from(draws) * (0.5 - score).powi(2)
+            + f64::from(losses) * score.powi(2))
+            / games;
+        let standard_error: f64 = (variance / games).sqrt();
+        // The margin is half the width of the interval the true score lies in 95% of the time, converted to Elo.
+        let low: f64 = elo_difference((score - 1.96 * standard_error).max(0.0));
+        let high: f64 = elo_difference((score + 1.96 * standard_error).min(1.0));
+        Some(Elo {
+            rating: elo_difference(score),
+            margin: (high - low) / 2.0,
+        })
+    }
+}
+
+/// Implementation to display the rating and margin (e.g. "+35 ± 40"), leaving out the margin of an infinite rating.
+impl std::fmt::Display for Elo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.rating.is_finite() {
+            write!(f, "{:+.0} ± {:.0}", self.rating, self.margin)
+        } else {
+            write!(f, "{:+.0}", self.rating)
+        }
+    }
+}
+
+/// Function that converts an average score (0 to 1) into the Elo rating difference that predicts it.
+pub fn elo_difference(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
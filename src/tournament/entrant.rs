@@ -0,0 +1,77 @@
+use std::{str::FromStr, time::Duration};
+
+use clap::ValueEnum;
+
+use crate::{
+    ai::{AiKind, Bot, Difficulty},
+    bot::ExternalBot,
+    AiArgs,
+};
+
+use super::TournamentError;
+
+/// Enum to represent what plays for an entrant: a built-in computer player or an external engine command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EntrantKind {
+    BuiltIn(AiArgs),
+    Engine(String),
+}
+
+/// Holds a player entered into a tournament; the name it's listed under and what plays for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entrant {
+    pub name: String,
+    pub kind: EntrantKind,
+}
+
+/// Implementation of FromStr for Entrant so that players can be given on the command line.
+/// Players are written as minimax[:difficulty], mcts[:difficulty] or engine:<command>, optionally preceded by name= to name them.
+impl FromStr for Entrant {
+    type Err = TournamentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TournamentError::InvalidPlayer(s.to_string());
+        // Only take a name from before the first '=' if it comes before the kind of player, since engine commands can contain '='.
+        let (name, spec) = s
+            .split_once('=')
+            .filter(|(name, _)| !name.contains(':'))
+            .unwrap_or((s, s));
+        let (kind, option) = spec.split_once(':').unwrap_or((spec, ""));
+        let kind: EntrantKind = match kind {
+            "engine" if !option.trim().is_empty() => EntrantKind::Engine(option.to_string()),
+            "minimax" | "mcts" => {
+                let difficulty: Difficulty = match option {
+                    "" => Difficulty::Perfect,
+                    option => Difficulty::from_str(option, true).map_err(|_| invalid())?,
+                };
+                EntrantKind::BuiltIn(AiArgs {
+                    ai: AiKind::from_str(kind, true).map_err(|_| invalid())?,
+                    difficulty,
+                    ..AiArgs::default()
+                })
+            }
+            _ => return Err(invalid()),
+        };
+        Ok(Entrant {
+            name: name.to_string(),
+            kind,
+        })
+    }
+}
+
+/// Implementation for Entrant containing a function to build its bot.
+impl Entrant {
+    /// Builds a bot for a single game; built-in players are given the seed (if any), engines the reply timeout.
+    pub fn bot(
+        &self,
+        seed: Option<u64>,
+        engine_timeout: Duration,
+    ) -> Result<Box<dyn Bot>, Box<dyn std::error::Error>> {
+        match &self.kind {
+            EntrantKind::BuiltIn(ai_args) => Ok(AiArgs { seed, ..*ai_args }.bot()),
+            EntrantKind::Engine(command) => {
+                Ok(Box::new(ExternalBot::spawn(command, engine_timeout)?))
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ai::Bot,
+    logic::{GameStatus, Move, Player},
+    BoardArgs, GameState, Generate,
+};
+
+/// Holds the record of a single tournament game, as written to the results file.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GameRecord {
+    /// The number of the game in the tournament, starting from 1.
+    pub game: usize,
+    /// Name of the player playing X.
+    pub x: String,
+    /// Name of the player playing O.
+    pub o: String,
+    pub status: GameStatus,
+    /// Why the game was lost by forfeit (an engine crashing, timing out or playing an illegal move), if it was.
+    pub forfeit: Option<String>,
+    pub moves: Vec<Move>,
+}
+
+/// Function that plays a game between two bots to the end.
+/// A bot that fails to produce a legal move forfeits the game, which is returned along with the reason.
+pub fn play_game(
+    boardargs: &BoardArgs,
+    x: &mut dyn Bot,
+    o: &mut dyn Bot,
+) -> Result<(GameState, Option<String>), Box<dyn std::error::Error>> {
+    let mut state: GameState = GameState::new(boardargs)?;
+    while state.status == GameStatus::Ongoing {
+        let player: Player = state.current_player;
+        let bot: &mut dyn Bot = match player {
+            Player::X => &mut *x,
+            Player::O => &mut *o,
+        };
+        if let Err(e) = bot
+            .choose_move(&state)
+            .and_then(|mv: Move| state.apply_move(mv))
+        {
+            return Ok(forfeit(state, player, e));
+        }
+    }
+    Ok((state, None))
+}
+
+/// Function that ends the game as a loss by forfeit for player, returning it along with the reason.
+pub fn forfeit(
+    mut state: GameState,
+    player: Player,
+    reason: Box<dyn std::error::Error>,
+) -> (GameState, Option<String>) {
+    state.status = GameStatus::Won(player.other());
+    (state, Some(format!("{} forfeits: {}", player, reason)))
+}
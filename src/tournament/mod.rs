@@ -0,0 +1,16 @@
+// Set module elo as public so that ratings can be estimated from results.
+pub mod elo;
+// Set module entrant as public so that players can be read from the command line.
+pub mod entrant;
+// Set module game_record as public so that games can be played headlessly and read back from the results file.
+pub mod game_record;
+// Set module run_tournament as public so that tournaments can be run from main.
+pub mod run_tournament;
+// Set module tournament_error as public so that tournament errors can be matched on.
+pub mod tournament_error;
+
+pub use elo::Elo;
+pub use entrant::{Entrant, EntrantKind};
+pub use game_record::{forfeit, play_game, GameRecord};
+pub use run_tournament::{run_tournament, Format, Tally};
+pub use tournament_error::TournamentError;
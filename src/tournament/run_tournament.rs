@@ -0,0 +1,218 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+
+use crate::{
+    logic::{GameStatus, Player},
+    BoardArgs, GameState, Generate, TournamentArgs,
+};
+
+use super::{forfeit, play_game, Elo, Entrant, GameRecord, TournamentError};
+
+/// Enum to represent who plays who in a tournament.
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Every player plays every other player.
+    #[default]
+    RoundRobin,
+    /// The first player plays every other player.
+    Gauntlet,
+}
+
+/// Implementation for Format containing a function to list the pairings.
+impl Format {
+    /// Returns the (index, index) pairs of the players who play each other, for a tournament of players players.
+    pub fn pairings(self, players: usize) -> Vec<(usize, usize)> {
+        match self {
+            Format::RoundRobin => (0..players)
+                .flat_map(|a: usize| (a + 1..players).map(move |b: usize| (a, b)))
+                .collect(),
+            Format::Gauntlet => (1..players).map(|b: usize| (0, b)).collect(),
+        }
+    }
+}
+
+/// Holds the number of wins, draws and losses of one player against another.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+/// Implementation for Tally containing functions to add results.
+impl Tally {
+    /// Adds the result of a game from the point of view of the player playing side.
+    pub fn record(&mut self, status: &GameStatus, side: Player) {
+        match status {
+            GameStatus::Won(winner) if *winner == side => self.wins += 1,
+            GameStatus::Won(_) => self.losses += 1,
+            _ => self.draws += 1,
+        }
+    }
+    /// Adds every result of another tally.
+    pub fn add(&mut self, other: Tally) {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+    }
+}
+
+/// Implementation to display a tally as "wins-draws-losses".
+impl std::fmt::Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}-{}", self.wins, self.draws, self.losses)
+    }
+}
+
+/// Function that plays a tournament between computer players without any terminal UI, writing every game to the results file and printing the standings.
+pub fn run_tournament(args: &TournamentArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let players: &[Entrant] = &args.players;
+    if players.len() < 2 {
+        return Err(Box::new(TournamentError::TooFewPlayers));
+    }
+    let boardargs: BoardArgs = BoardArgs {
+        shape: args.shape,
+        ..BoardArgs::default()
+    };
+    let engine_timeout: Duration = Duration::from_millis(args.engine_timeout);
+    let mut results = BufWriter::new(File::create(&args.results)?);
+    // tallies[a][b] holds the results of player a against player b.
+    let mut tallies: Vec<Vec<Tally>> = vec![vec![Tally::default(); players.len()]; players.len()];
+    let mut game: usize = 0;
+
+    for (a, b) in args.format.pairings(players.len()) {
+        for round in 0..args.games {
+            // Alternate colours, with the first player of the pairing starting as X.
+            let (x, o) = if round % 2 == 0 { (a, b) } else { (b, a) };
+            game += 1;
+            // Give every game its own seed so that seeded players don't just replay the same game.
+            let seed: Option<u64> = args.seed.map(|seed: u64| seed.wrapping_add(game as u64));
+            let x_bot = players[x].bot(seed, engine_timeout);
+            let o_bot = players[o].bot(seed.map(|seed: u64| !seed), engine_timeout);
+            // An engine that can't be started forfeits the game, just as one that crashes during it does (X first, if neither can be).
+            let (state, forfeit) = match (x_bot, o_bot) {
+                (Ok(mut x_bot), Ok(mut o_bot)) => {
+                    play_game(&boardargs, x_bot.as_mut(), o_bot.as_mut())?
+                }
+                (Err(e), _) => forfeit(GameState::new(&boardargs)?, Player::X, e),
+                (_, Err(e)) => forfeit(GameState::new(&boardargs)?, Player::O, e),
+            };
+
+            tallies[x][o].record(&state.status, Player::X);
+            tallies[o][x].record(&state.status, Player::O);
+            let record: GameRecord = GameRecord {
+                game,
+                x: players[x].name.clone(),
+                o: players[o].name.clone(),
+                status: state.status,
+                forfeit,
+                moves: state.history,
+            };
+            writeln!(results, "{}", serde_json::to_string(&record)?)?;
+            println!("{}", describe_game(&record));
+        }
+    }
+    results.flush()?;
+
+    println!();
+    print_crosstable(players, &tallies);
+    println!();
+    print_standings(players, &tallies);
+    println!();
+    println!("Wrote {} games to {}", game, args.results);
+    Ok(())
+}
+
+/// Function that describes the result of a game in a single line.
+fn describe_game(record: &GameRecord) -> String {
+    let result: String = match &record.status {
+        GameStatus::Won(Player::X) => format!("{} wins", record.x),
+        GameStatus::Won(Player::O) => format!("{} wins", record.o),
+        _ => String::from("draw"),
+    };
+    let forfeit: String = match &record.forfeit {
+        Some(reason) => format!(" ({})", reason),
+        None => String::new(),
+    };
+    format!(
+        "Game {}: {} (X) vs {} (O): {}{}",
+        record.game, record.x, record.o, result, forfeit
+    )
+}
+
+/// Function that prints the results of every player against every other player, as wins-draws-losses of the row's player.
+fn print_crosstable(players: &[Entrant], tallies: &[Vec<Tally>]) {
+    let width: usize = column_width(players);
+    print!("{:width$}", "");
+    for player in players {
+        print!(" {:>width$}", player.name);
+    }
+    println!();
+    for (a, player) in players.iter().enumerate() {
+        print!("{:width$}", player.name);
+        for (b, tally) in tallies[a].iter().enumerate() {
+            let cell: String = if a == b {
+                String::from("-")
+            } else {
+                tally.to_string()
+            };
+            print!(" {:>width$}", cell);
+        }
+        println!();
+    }
+}
+
+/// Function that prints every player's total wins, draws and losses, score and Elo estimate, best first.
+fn print_standings(players: &[Entrant], tallies: &[Vec<Tally>]) {
+    let width: usize = column_width(players);
+    let mut standings: Vec<(&Entrant, Tally)> = players
+        .iter()
+        .zip(tallies)
+        .map(|(player, row): (&Entrant, &Vec<Tally>)| {
+            let mut total: Tally = Tally::default();
+            row.iter().for_each(|tally: &Tally| total.add(*tally));
+            (player, total)
+        })
+        .collect();
+    standings.sort_by_key(|(_, total): &(&Entrant, Tally)| {
+        std::cmp::Reverse(2 * total.wins + total.draws)
+    });
+
+    println!(
+        "{:width$} {:>6} {:>5} {:>5} {:>5} {:>7} {:>14}",
+        "Player", "Games", "W", "D", "L", "Score", "Elo"
+    );
+    for (player, total) in standings {
+        let games: u32 = total.wins + total.draws + total.losses;
+        let score: f64 =
+            (f64::from(total.wins) + 0.5 * f64::from(total.draws)) / f64::from(games.max(1));
+        let elo: String = Elo::from_results(total.wins, total.draws, total.losses)
+            .map_or_else(|| String::from("-"), |elo: Elo| elo.to_string());
+        println!(
+            "{:width$} {:>6} {:>5} {:>5} {:>5} {:>6.1}% {:>14}",
+            player.name,
+            games,
+            total.wins,
+            total.draws,
+            total.losses,
+            100.0 * score,
+            elo
+        );
+    }
+}
+
+/// Function that returns how wide the columns need to be to fit every player's name.
+fn column_width(players: &[Entrant]) -> usize {
+    players
+        .iter()
+        .map(|player: &Entrant| player.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Player".len())
+        .max(7)
+}
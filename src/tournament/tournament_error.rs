@@ -0,0 +1,23 @@
+/// Errors that can occur while setting up a tournament.
+#[derive(Debug)]
+pub enum TournamentError {
+    TooFewPlayers,
+    InvalidPlayer(String),
+}
+
+/// Implementation of std::fmt::Display for TournamentError to display message based on error.
+impl std::fmt::Display for TournamentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TournamentError::TooFewPlayers => write!(f, "A tournament needs at least two players."),
+            TournamentError::InvalidPlayer(player) => write!(
+                f,
+                "Invalid player {:?}; expected [name=]minimax[:difficulty], [name=]mcts[:difficulty] or [name=]engine:<command>.",
+                player
+            ),
+        }
+    }
+}
+
+/// Implementation of std::error::Error for TournamentError so that TournamentError is seen as an error type.
+impl std::error::Error for TournamentError {}
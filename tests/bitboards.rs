@@ -0,0 +1,55 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use tic_tac_toe_rs::{
+    logic::{Bitboard, Cell, Move, Rules, Symbol},
+    GameState, GameStatus,
+};
+
+mod common;
+use common::random_game;
+
+/// Asserts that the bitboard of each symbol holds exactly the cells marked with it on the board.
+fn assert_bitboards_match(state: &GameState) {
+    for symbol in [Symbol::X, Symbol::O] {
+        let bitboard: &Bitboard = state.bitboard(symbol);
+        for (index, cell) in state.board.iter().enumerate() {
+            assert_eq!(
+                bitboard.get(index),
+                *cell == Cell::Occupied(symbol),
+                "{} at {}",
+                symbol,
+                index
+            );
+        }
+        let marks: usize = state
+            .board
+            .iter()
+            .filter(|cell: &&Cell| **cell == Cell::Occupied(symbol))
+            .count();
+        assert_eq!(bitboard.count(), marks);
+    }
+}
+
+/// Plays random games on random boards (small enough for one integer and too big for one, some of them 3D) with random rules, randomly taking moves back and redoing them, checking the bitboards against the board after every change.
+#[test]
+fn bitboards_match_board_on_random_games() {
+    let mut rng: StdRng = StdRng::seed_from_u64(15);
+    for _ in 0..500 {
+        let mut state: GameState = random_game(&mut rng);
+        assert_bitboards_match(&state);
+        while state.status == GameStatus::Ongoing {
+            let mv: Move = *state.legal_moves().choose(&mut rng).unwrap();
+            state.apply_move(mv).unwrap();
+            assert_bitboards_match(&state);
+            if rng.gen_bool(0.2) {
+                state.undo().unwrap();
+                assert_bitboards_match(&state);
+                state.redo().unwrap();
+                assert_bitboards_match(&state);
+            }
+        }
+        // Setting the board directly (as turning it does) rebuilds them too.
+        for symmetry in state.variant.symmetries(state.geometry) {
+            assert_bitboards_match(&state.transformed(symmetry));
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use tic_tac_toe_rs::{
+    bot::protocol,
+    logic::{Move, Symbol, Variant},
+    BoardArgs, BoardShapeArgs, GameState, GameStatus, Generate, Geometry, Player,
+};
+
+/// Every newgame command reads back as the size, win length and variant it was written from, square or not.
+#[test]
+fn newgame_round_trips() {
+    for geometry in [
+        Geometry::new(3, 3),
+        Geometry::new(7, 6),
+        Geometry::new(1, 12),
+    ] {
+        for variant in Variant::ALL {
+            let encoded: String = protocol::encode_newgame(geometry, 4, variant);
+            assert_eq!(
+                protocol::decode_newgame(&encoded).unwrap(),
+                (geometry, 4, variant),
+                "{}",
+                encoded
+            );
+        }
+    }
+}
+
+/// Every move reads back as itself, whether it's a plain mark, a picked symbol or a number.
+#[test]
+fn move_round_trips() {
+    for player in [Player::X, Player::O] {
+        for mv in [
+            Move::new(player, 0, 0),
+            Move::new(player, 6, 5),
+            Move::new(player, 2, 1).with_symbol(Symbol::X),
+            Move::new(player, 1, 2).with_symbol(Symbol::O),
+            Move::new(player, 1, 1).with_number(9),
+        ] {
+            let encoded: String = protocol::encode_move(mv);
+            assert_eq!(protocol::decode_move(&encoded, player).unwrap(), mv);
+        }
+    }
+}
+
+/// Positions from random games (under every variant) read back with the same board and player to move, and as still going.
+#[test]
+fn position_round_trips() {
+    let mut rng: StdRng = StdRng::seed_from_u64(11);
+    for variant in Variant::ALL {
+        // Numerical tic-tac-toe is only played on a 3x3 board with lines of three.
+        let (geometry, win_length) = match variant {
+            Variant::Numerical => (Geometry::new(3, 3), 3),
+            _ => (Geometry::new(5, 4), 3),
+        };
+        for _ in 0..50 {
+            let mut state: GameState = GameState::new(&BoardArgs {
+                shape: BoardShapeArgs {
+                    width: Some(geometry.width),
+                    height: Some(geometry.height),
+                    win_length: Some(win_length),
+                    variant,
+                    ..BoardShapeArgs::default()
+                },
+                ..BoardArgs::default()
+            })
+            .unwrap();
+            // Engines are only sent positions they have to move in, so only ongoing positions are checked.
+            // (A finished one can't always be read back, since the board doesn't say who completed a line of the other's symbol.)
+            while state.status == GameStatus::Ongoing {
+                let encoded: String = protocol::encode_position(&state);
+                let decoded: GameState =
+                    protocol::decode_position(&encoded, geometry, win_length, variant).unwrap();
+                assert_eq!(decoded.board, state.board, "{}", encoded);
+                assert_eq!(decoded.current_player, state.current_player, "{}", encoded);
+                assert_eq!(decoded.status, GameStatus::Ongoing, "{}", encoded);
+                let mv: Move = *state.legal_moves().choose(&mut rng).unwrap();
+                state.apply_move(mv).unwrap();
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use tic_tac_toe_rs::{
+    ai::{Solution, Solver},
+    logic::{Move, Rules, Symmetry, Variant},
+    BoardArgs, BoardShapeArgs, GameState, GameStatus, Generate,
+};
+
+/// Plays random games on square and oblong boards under every variant, checking that every rotation or reflection the rules allow has the same canonical hash as the position itself.
+#[test]
+fn symmetric_positions_share_canonical_hash() {
+    let mut rng: StdRng = StdRng::seed_from_u64(14);
+    for _ in 0..300 {
+        let variant: Variant = *Variant::ALL.choose(&mut rng).unwrap();
+        let (width, height) = match variant {
+            Variant::Numerical => (3, 3),
+            _ => (rng.gen_range(2..=5), rng.gen_range(2..=5)),
+        };
+        let mut state: GameState = GameState::new(&BoardArgs {
+            shape: BoardShapeArgs {
+                width: Some(width),
+                height: Some(height),
+                win_length: Some(3.min(width.max(height))),
+                variant,
+                ..BoardShapeArgs::default()
+            },
+            ..BoardArgs::default()
+        })
+        .unwrap();
+        while state.status == GameStatus::Ongoing {
+            let hash: u64 = state.canonical_hash();
+            for symmetry in state.variant.symmetries(state.geometry) {
+                let symmetric: GameState = state.transformed(symmetry);
+                assert_eq!(symmetric.canonical_hash(), hash, "{:?}", symmetry);
+                assert_eq!(
+                    symmetric.zobrist_hash(),
+                    state.symmetric_hash(symmetry),
+                    "{:?}",
+                    symmetry
+                );
+            }
+            let mv: Move = *state.legal_moves().choose(&mut rng).unwrap();
+            state.apply_move(mv).unwrap();
+        }
+    }
+}
+
+/// Positions that are only the same after a rotation the rules don't allow (marks fall down the board under gravity) don't share a canonical hash.
+#[test]
+fn gravity_positions_only_share_mirrored_hashes() {
+    let mut state: GameState = GameState::new(&BoardArgs {
+        shape: BoardShapeArgs {
+            size: Some(3),
+            variant: Variant::Gravity,
+            ..BoardShapeArgs::default()
+        },
+        ..BoardArgs::default()
+    })
+    .unwrap();
+    state
+        .apply_move(Move::new(state.current_player, 0, 2))
+        .unwrap();
+    let mirrored: GameState = state.transformed(Symmetry::FlipHorizontal);
+    let flipped: GameState = state.transformed(Symmetry::FlipVertical);
+    assert_eq!(mirrored.canonical_hash(), state.canonical_hash());
+    assert_ne!(flipped.canonical_hash(), state.canonical_hash());
+}
+
+/// The solver (whose table is keyed by the canonical hash) finds the empty 3x3 board a draw with every move drawing.
+#[test]
+fn solver_draws_empty_board() {
+    let state: GameState = GameState::new(&BoardArgs::default()).unwrap();
+    let solution: Solution = Solver::new().solve(&state).unwrap();
+    assert_eq!(solution.score, 0);
+    assert_eq!(solution.best_moves.len(), 9);
+}
+
+/// Boards that only differ in how many layers they have don't share a hash.
+#[test]
+fn boards_with_different_depths_hash_differently() {
+    let hashes: Vec<u64> = (1..=4)
+        .map(|depth: usize| {
+            GameState::new(&BoardArgs {
+                shape: BoardShapeArgs {
+                    size: Some(4),
+                    depth: Some(depth),
+                    win_length: Some(4),
+                    ..BoardShapeArgs::default()
+                },
+                ..BoardArgs::default()
+            })
+            .unwrap()
+            .zobrist_hash()
+        })
+        .collect();
+    for (a, hash) in hashes.iter().enumerate() {
+        assert!(!hashes[a + 1..].contains(hash), "depth {}", a + 1);
+    }
+}
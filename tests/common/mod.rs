@@ -0,0 +1,33 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use tic_tac_toe_rs::{logic::Variant, BoardArgs, BoardShapeArgs, GameState, Generate};
+
+/// Function that starts a game with random rules on a random board; flat or 3D, and small enough for one integer of bitboard or too big for one.
+pub fn random_game(rng: &mut StdRng) -> GameState {
+    let variant: Variant = *Variant::ALL.choose(rng).unwrap();
+    let width: usize = rng.gen_range(1..=12);
+    let height: usize = rng.gen_range(1..=12);
+    let depth: usize = if rng.gen_bool(0.25) {
+        rng.gen_range(2..=5)
+    } else {
+        1
+    };
+    let win_length: usize = rng.gen_range(1..=width.max(height).max(depth));
+    // Numerical tic-tac-toe is only played on a flat 3x3 board with lines of three.
+    let (width, height, depth, win_length) = if variant == Variant::Numerical {
+        (3, 3, 1, 3)
+    } else {
+        (width, height, depth, win_length)
+    };
+    GameState::new(&BoardArgs {
+        shape: BoardShapeArgs {
+            width: Some(width),
+            height: Some(height),
+            depth: Some(depth),
+            win_length: Some(win_length),
+            variant,
+            ..BoardShapeArgs::default()
+        },
+        ..BoardArgs::default()
+    })
+    .unwrap()
+}
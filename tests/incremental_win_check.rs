@@ -0,0 +1,31 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use tic_tac_toe_rs::{logic::Move, GameState, GameStatus, Generate};
+
+mod common;
+use common::random_game;
+
+/// Plays random games on random boards (some of them 3D) with random rules, checking after every move that the incremental check through the last mark agrees with a full scan of the board.
+#[test]
+fn incremental_check_matches_full_scan_on_random_games() {
+    let mut rng: StdRng = StdRng::seed_from_u64(16);
+    for _ in 0..2000 {
+        let mut state: GameState = random_game(&mut rng);
+        while state.status == GameStatus::Ongoing {
+            let mv: Move = *state.legal_moves().choose(&mut rng).unwrap();
+            state.apply_move(mv).unwrap();
+            let (status, winning_lines) = GameState::check_winner(&state).unwrap();
+            assert_eq!(state.status, status, "status after {:?}", state.history);
+            assert_eq!(
+                state.winning_lines, winning_lines,
+                "winning lines after {:?}",
+                state.history
+            );
+            // Taking the move back and replaying it has to give the same result again.
+            if rng.gen_bool(0.2) {
+                state.undo().unwrap();
+                assert_eq!(state.redo().unwrap(), status);
+                assert_eq!(state.winning_lines, winning_lines);
+            }
+        }
+    }
+}
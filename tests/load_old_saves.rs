@@ -0,0 +1,162 @@
+use tic_tac_toe_rs::{
+    ai::{AiKind, Difficulty, Opponent},
+    cli::saved_game::SavedGame,
+    logic::{Cell, GameError, Move, Symbol},
+    BoardArgs, GameState, GameStatus, Generate, Geometry, Player,
+};
+
+/// Function that gives the path of a save file in the temp directory for the named test, which no other test (or run of the tests going on at the same time) writes to.
+fn temp_save(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "tic_tac_toe_rs_{}_{}.json",
+        std::process::id(),
+        name
+    ))
+}
+
+/// Writes json to a save file of its own in the temp directory and loads it back as a game, removing the file even if it can't be loaded.
+fn load(name: &str, json: &str) -> GameState {
+    let path: std::path::PathBuf = temp_save(name);
+    std::fs::write(&path, json).unwrap();
+    let result = GameState::load_game(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap()
+}
+
+/// A game saved without a win length is loaded with lines as long as the board's shorter side.
+#[test]
+fn save_without_win_length_uses_shorter_side() {
+    let state: GameState = load(
+        "save_without_win_length_uses_shorter_side",
+        r#"{"board":["X"," "," "," "," "," "," "," "," "," "," "," "],"geometry":{"width":4,"height":3},"current_player":"O","status":"Ongoing"}"#,
+    );
+    assert_eq!(state.win_length, 3);
+    assert_eq!(state.board[0], Cell::Occupied(Symbol::X));
+    assert_eq!(state.current_player, Player::O);
+    assert_eq!(state.status, GameStatus::Ongoing);
+}
+
+/// A game saved before boards had dimensions (or a win length) is loaded as a square board with lines across it.
+#[test]
+fn save_without_geometry_is_square() {
+    let state: GameState = load(
+        "save_without_geometry_is_square",
+        r#"{"board":["X","O"," "," ","X"," "," "," "," "],"current_player":"O","status":"Ongoing"}"#,
+    );
+    assert_eq!(state.geometry, Geometry::new(3, 3));
+    assert_eq!(state.win_length, 3);
+    assert_eq!(state.occupied, 3);
+    assert_eq!(state.board[1], Cell::Occupied(Symbol::O));
+}
+
+/// A game saved with its opponent is loaded with the same opponent, and a save without one is a game between two people.
+#[test]
+fn saved_game_keeps_its_opponent() {
+    let boardargs: BoardArgs = BoardArgs {
+        vs_ai: Some(Player::O),
+        ..BoardArgs::default()
+    };
+    let mut saved: SavedGame = SavedGame::new(&boardargs).unwrap();
+    saved.state.apply_move(Move::new(Player::X, 1, 1)).unwrap();
+    let path: std::path::PathBuf = temp_save("saved_game_keeps_its_opponent");
+    saved.save_game(path.to_str().unwrap()).unwrap();
+    let loaded = SavedGame::load_game(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    let loaded: SavedGame = loaded.unwrap();
+    assert_eq!(loaded.opponent, Opponent::from_args(&boardargs));
+    assert_eq!(loaded.state.board, saved.state.board);
+    assert_eq!(loaded.state.history, saved.state.history);
+    assert!(loaded.is_computer_turn());
+
+    let state: GameState = load(
+        "saved_game_keeps_its_opponent_without_one",
+        r#"{"board":[" "," "," "," "," "," "," "," "," "],"geometry":{"width":3,"height":3},"current_player":"X","status":"Ongoing","win_length":3}"#,
+    );
+    assert_eq!(state.occupied, 0);
+    let path: std::path::PathBuf = temp_save("saved_game_keeps_its_opponent_resaved");
+    state.save_game(path.to_str().unwrap()).unwrap();
+    let loaded = SavedGame::load_game(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    let loaded: SavedGame = loaded.unwrap();
+    assert_eq!(loaded.opponent, None);
+    assert!(!loaded.is_computer_turn());
+}
+
+/// An opponent saved before it shared its fields with the command line arguments is loaded with the same settings.
+#[test]
+fn save_with_old_opponent_fields() {
+    let json: &str = r#"{"board":[" "," "," "," "," "," "," "," "," "],"geometry":{"width":3,"height":3},"current_player":"X","status":"Ongoing","win_length":3,"opponent":{"side":"O","kind":"Mcts","difficulty":"Hard","iterations":200,"time_limit_ms":50,"seed":7}}"#;
+    let path: std::path::PathBuf = temp_save("save_with_old_opponent_fields");
+    std::fs::write(&path, json).unwrap();
+    let loaded = SavedGame::load_game(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    let loaded: SavedGame = loaded.unwrap();
+    let opponent: Opponent = loaded.opponent.unwrap();
+    assert_eq!(opponent.side, Player::O);
+    assert_eq!(opponent.ai_args.ai, AiKind::Mcts);
+    assert_eq!(opponent.ai_args.difficulty, Difficulty::Hard);
+    assert_eq!(opponent.ai_args.ai_iterations, Some(200));
+    assert_eq!(opponent.ai_args.ai_time, Some(50));
+    assert_eq!(opponent.ai_args.seed, Some(7));
+    assert_eq!(opponent.engine, None);
+}
+
+/// Saves whose board doesn't fit its dimensions, or whose history doesn't match the marks on the board, are rejected rather than loaded.
+#[test]
+fn save_with_mismatched_board_or_history_is_rejected() {
+    let saves: [(&str, &str, GameError); 4] = [
+        (
+            "not_square",
+            r#"{"board":[" "," "," "," "," "," "," "," "," "," "],"current_player":"X","status":"Ongoing"}"#,
+            GameError::InvalidBoardSize,
+        ),
+        (
+            "long_history",
+            r#"{"board":["X"," "," "," "," "," "," "," "," "],"geometry":{"width":3,"height":3},"current_player":"X","status":"Ongoing","win_length":3,"history":[{"player":"X","x":0,"y":0},{"player":"O","x":1,"y":0}]}"#,
+            GameError::InvalidHistory,
+        ),
+        (
+            "history_off_board",
+            r#"{"board":["X"," "," "," "," "," "," "," "," "],"geometry":{"width":3,"height":3},"current_player":"O","status":"Ongoing","win_length":3,"history":[{"player":"X","x":5,"y":0}]}"#,
+            GameError::InvalidHistory,
+        ),
+        (
+            "history_wrong_symbol",
+            r#"{"board":["X"," "," "," "," "," "," "," "," "],"geometry":{"width":3,"height":3},"current_player":"O","status":"Ongoing","win_length":3,"history":[{"player":"O","x":0,"y":0}]}"#,
+            GameError::InvalidHistory,
+        ),
+    ];
+    for (name, json, expected) in saves {
+        let path: std::path::PathBuf = temp_save(&format!(
+            "save_with_mismatched_board_or_history_is_rejected_{}",
+            name
+        ));
+        std::fs::write(&path, json).unwrap();
+        let result = GameState::load_game(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+        let error: Box<dyn std::error::Error> = result.unwrap_err();
+        assert_eq!(
+            error
+                .downcast_ref::<GameError>()
+                .map(|e: &GameError| e.to_string()),
+            Some(expected.to_string()),
+            "{}",
+            name
+        );
+    }
+}
+
+/// Taking back a move whose mark has gone from the board (the board having been set directly) is an error that leaves the game as it was, rather than a panic.
+#[test]
+fn undo_of_missing_mark_is_rejected() {
+    let mut state: GameState = GameState::new(&BoardArgs::default()).unwrap();
+    state.apply_move(Move::new(Player::X, 1, 1)).unwrap();
+    state.board[4] = Cell::Empty;
+    let error: Box<dyn std::error::Error> = state.undo().unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<GameError>(),
+        Some(GameError::InvalidHistory)
+    ));
+    assert_eq!(state.history.len(), 1);
+    assert_eq!(state.current_player, Player::O);
+}
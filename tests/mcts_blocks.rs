@@ -0,0 +1,72 @@
+use tic_tac_toe_rs::{
+    ai::{Bot, Mcts},
+    logic::{Move, Symbol, Variant},
+    BoardArgs, BoardShapeArgs, GameState, GameStatus, Generate, Player,
+};
+
+/// Plays each move in turn, panicking if any of them is rejected.
+fn play(state: &mut GameState, moves: &[Move]) {
+    for &mv in moves {
+        state.apply_move(mv).unwrap();
+    }
+}
+
+/// Chaos blocks Order's line of four with the other symbol, rather than copying Order's move and completing the line for them.
+#[test]
+fn chaos_blocks_with_the_other_symbol() {
+    let mut state: GameState = GameState::new(&BoardArgs {
+        shape: BoardShapeArgs {
+            variant: Variant::OrderChaos,
+            ..BoardShapeArgs::default()
+        },
+        ..BoardArgs::default()
+    })
+    .unwrap();
+    // Order has X in the first four cells of the top row, and would win with an X in the fifth (leaving the sixth empty).
+    play(
+        &mut state,
+        &[
+            Move::new(Player::X, 0, 0).with_symbol(Symbol::X),
+            Move::new(Player::O, 0, 5).with_symbol(Symbol::O),
+            Move::new(Player::X, 1, 0).with_symbol(Symbol::X),
+            Move::new(Player::O, 2, 5).with_symbol(Symbol::O),
+            Move::new(Player::X, 2, 0).with_symbol(Symbol::X),
+            Move::new(Player::O, 4, 5).with_symbol(Symbol::O),
+            Move::new(Player::X, 3, 0).with_symbol(Symbol::X),
+        ],
+    );
+    let mv: Move = Mcts::new(Some(50), None, Some(22))
+        .choose_move(&state)
+        .unwrap();
+    assert_eq!(mv.player, Player::O);
+    state.apply_move(mv).unwrap();
+    assert_eq!(state.status, GameStatus::Ongoing, "{:?}", mv);
+}
+
+/// O blocks X's line in numerical tic-tac-toe with one of its own even numbers, rather than copying X's odd one (which O can't play).
+#[test]
+fn numerical_block_uses_own_number() {
+    let mut state: GameState = GameState::new(&BoardArgs {
+        shape: BoardShapeArgs {
+            variant: Variant::Numerical,
+            ..BoardShapeArgs::default()
+        },
+        ..BoardArgs::default()
+    })
+    .unwrap();
+    // X has 1 and 5 in the top row, and would win with 9 in its last cell.
+    play(
+        &mut state,
+        &[
+            Move::new(Player::X, 0, 0).with_number(1),
+            Move::new(Player::O, 2, 2).with_number(2),
+            Move::new(Player::X, 1, 0).with_number(5),
+        ],
+    );
+    let mv: Move = Mcts::new(Some(50), None, Some(25))
+        .choose_move(&state)
+        .unwrap();
+    assert_eq!((mv.player, mv.x, mv.y), (Player::O, 2, 0));
+    state.apply_move(mv).unwrap();
+    assert_eq!(state.status, GameStatus::Ongoing, "{:?}", mv);
+}
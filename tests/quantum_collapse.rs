@@ -0,0 +1,114 @@
+use tic_tac_toe_rs::{
+    logic::{GameError, QuantumMark, QuantumState},
+    GameStatus, Player,
+};
+
+/// A spooky mark; the player who plays it and the (x, y) of its two cells.
+type Spooky = (Player, (usize, usize), (usize, usize));
+
+/// Plays a game where the spooky marks X1 to X5 and O2 to O6 make a cycle through the top two rows (X1 in 0 and 3, O2 in 3 and 1, X3 in 1 and 4, O4 in 4 and 2, X5 in 2 and 5, and O6 in 5 and 0 closing it),
+/// returning it with the collapse of O6 pending.
+fn closed_cycle() -> QuantumState {
+    let mut state: QuantumState = QuantumState::new();
+    let moves: [Spooky; 6] = [
+        (Player::X, (0, 0), (0, 1)),
+        (Player::O, (0, 1), (1, 0)),
+        (Player::X, (1, 0), (1, 1)),
+        (Player::O, (1, 1), (2, 0)),
+        (Player::X, (2, 0), (2, 1)),
+        (Player::O, (2, 1), (0, 0)),
+    ];
+    for (player, first, second) in moves {
+        assert_eq!(state.pending_collapse, None);
+        assert_eq!(
+            state.apply_move(player, first, second).unwrap(),
+            GameStatus::Ongoing
+        );
+    }
+    state
+}
+
+/// The last mark of the chain closes a cycle, and nothing can be played until the other player collapses it into one of its own two cells.
+#[test]
+fn closing_a_cycle_waits_for_a_collapse() {
+    let mut state: QuantumState = closed_cycle();
+    assert_eq!(state.pending_collapse, Some(5));
+    assert_eq!(state.current_player, Player::X);
+    assert!(state.is_entangled(0, 5));
+    assert!(!state.is_entangled(0, 8));
+    assert!(matches!(
+        state
+            .apply_move(Player::X, (2, 2), (1, 2))
+            .unwrap_err()
+            .downcast_ref::<GameError>(),
+        Some(GameError::CollapsePending)
+    ));
+    assert!(matches!(
+        state
+            .collapse(Player::O, (2, 1))
+            .unwrap_err()
+            .downcast_ref::<GameError>(),
+        Some(GameError::NotYourTurn)
+    ));
+    assert!(matches!(
+        state
+            .collapse(Player::X, (1, 1))
+            .unwrap_err()
+            .downcast_ref::<GameError>(),
+        Some(GameError::InvalidCollapse)
+    ));
+    assert_eq!(state.pending_collapse, Some(5));
+}
+
+/// Collapsing O6 into the middle right cell settles every mark in the cycle, giving X the top row and O the middle row at once.
+/// X's line has the lower highest subscript (5 against 6), so X wins and scores 1 while O scores ½.
+#[test]
+fn collapse_with_lines_for_both_splits_the_points() {
+    let mut state: QuantumState = closed_cycle();
+    assert_eq!(
+        state.collapse(Player::X, (2, 1)).unwrap(),
+        GameStatus::Won(Player::X)
+    );
+    assert_eq!(state.pending_collapse, None);
+    let collapsed: Vec<Option<usize>> = state
+        .marks
+        .iter()
+        .map(|mark: &QuantumMark| mark.collapsed)
+        .collect();
+    assert_eq!(
+        collapsed,
+        vec![Some(0), Some(3), Some(1), Some(4), Some(2), Some(5)]
+    );
+    assert_eq!(
+        state.winning_lines,
+        vec![(Player::X, 5, vec![0, 1, 2]), (Player::O, 6, vec![3, 4, 5])]
+    );
+    assert_eq!(state.points(Player::X), 1.0);
+    assert_eq!(state.points(Player::O), 0.5);
+}
+
+/// Collapsing O6 into the top left cell settles the cycle the other way round, giving X the middle row and O the top row instead.
+/// X's line still has the lower highest subscript, so the points are split the same way.
+#[test]
+fn collapse_the_other_way_swaps_the_rows() {
+    let mut state: QuantumState = closed_cycle();
+    assert_eq!(
+        state.collapse(Player::X, (0, 0)).unwrap(),
+        GameStatus::Won(Player::X)
+    );
+    let collapsed: Vec<Option<usize>> = state
+        .marks
+        .iter()
+        .map(|mark: &QuantumMark| mark.collapsed)
+        .collect();
+    assert_eq!(
+        collapsed,
+        vec![Some(3), Some(1), Some(4), Some(2), Some(5), Some(0)]
+    );
+    assert_eq!(
+        state.winning_lines,
+        vec![(Player::X, 5, vec![3, 4, 5]), (Player::O, 6, vec![0, 1, 2])]
+    );
+    assert_eq!(state.points(Player::X), 1.0);
+    assert_eq!(state.points(Player::O), 0.5);
+}